@@ -0,0 +1,30 @@
+//! `cargo bench -p aoc` times every entry from the answers.toml manifests.
+//! Filter with e.g. `cargo bench -p aoc -- day_05/part2`, and set
+//! `AOC_BENCH_SLOW=1` to include inputs marked as slow.
+
+use std::hint::black_box;
+
+use aoc::verify;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    let slow = std::env::var_os("AOC_BENCH_SLOW").is_some();
+    for day in 1..=25 {
+        let expectations = verify::expectations(day..=day, slow).unwrap();
+        if expectations.is_empty() {
+            continue;
+        }
+        let mut group = c.benchmark_group(format!("day_{:02}", day));
+        group.sample_size(10);
+        for e in expectations {
+            let input = std::fs::read_to_string(aoc::day_file(day, &e.file)).unwrap();
+            group.bench_function(format!("part{}/{}", e.part, e.file), |b| {
+                b.iter(|| aoc::solve(day, e.part, black_box(&input)).unwrap())
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::verify::Expectation;
+
+/// Median wall time of one day/part/input, as shown in the summary table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub file: String,
+    pub median: Duration,
+}
+
+type Key = (u8, u8, String);
+
+impl Timing {
+    fn key(&self) -> Key {
+        (self.day, self.part, self.file.clone())
+    }
+}
+
+/// Runs one manifest entry `runs` times. Anything taking over a second is
+/// only run once, there's no point waiting minutes for a median.
+pub fn measure(expectation: &Expectation, runs: usize) -> Result<Timing, String> {
+    let path = crate::day_file(expectation.day, &expectation.file);
+    let input = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut times = vec![];
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let answer = crate::solve(expectation.day, expectation.part, &input)
+            .map_err(|e| e.with_file(&expectation.file).to_string())?;
+        times.push(start.elapsed());
+        std::hint::black_box(answer);
+        if times[0] > Duration::from_secs(1) {
+            break;
+        }
+    }
+    times.sort();
+    Ok(Timing {
+        day: expectation.day,
+        part: expectation.part,
+        file: expectation.file.clone(),
+        median: times[times.len() / 2],
+    })
+}
+
+/// Tab separated, one timing per line, so results can be kept around and
+/// compared against after a change.
+pub fn save(timings: &[Timing]) -> String {
+    let mut ret = String::from("# day\tpart\tfile\tnanos\n");
+    for t in timings {
+        writeln!(ret, "{}\t{}\t{}\t{}", t.day, t.part, t.file, t.median.as_nanos()).unwrap();
+    }
+    ret
+}
+
+pub fn load(text: &str) -> Result<Vec<Timing>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.starts_with('#') && !l.trim().is_empty())
+        .map(|(idx, l)| {
+            let err = || format!("line {}: expected day, part, file and nanos separated by tabs", idx + 1);
+            let [day, part, file, nanos] = l.split('\t').collect::<Vec<_>>()[..] else {
+                return Err(err());
+            };
+            Ok(Timing {
+                day: day.parse().map_err(|_| err())?,
+                part: part.parse().map_err(|_| err())?,
+                file: file.to_owned(),
+                median: Duration::from_nanos(nanos.parse().map_err(|_| err())?),
+            })
+        })
+        .collect()
+}
+
+pub fn table(timings: &[Timing], baseline: Option<&[Timing]>) -> String {
+    let baseline: HashMap<Key, Duration> = baseline.unwrap_or_default().iter().map(|t| (t.key(), t.median)).collect();
+    let mut ret = String::new();
+    writeln!(ret, "{:>3} {:>4} {:<20} {:>12}", "day", "part", "file", "median").unwrap();
+    if !baseline.is_empty() {
+        ret.pop();
+        writeln!(ret, " {:>12} {:>8}", "baseline", "change").unwrap();
+    }
+    for t in timings {
+        write!(ret, "{:>3} {:>4} {:<20} {:>12}", t.day, t.part, t.file, format!("{:.1?}", t.median)).unwrap();
+        if let Some(old) = baseline.get(&t.key()) {
+            let change = (t.median.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+            write!(ret, " {:>12} {:>7.1}%", format!("{:.1?}", old), change).unwrap();
+        }
+        ret.push('\n');
+    }
+    let total: Duration = timings.iter().map(|t| t.median).sum();
+    writeln!(ret, "{:>3} {:>4} {:<20} {:>12}", "", "", "total", format!("{:.1?}", total)).unwrap();
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, part: u8, file: &str, millis: u64) -> Timing {
+        Timing { day, part, file: file.to_owned(), median: Duration::from_millis(millis) }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let timings = vec![timing(5, 2, "input.txt", 3), timing(12, 1, "input2.txt", 1500)];
+        assert_eq!(load(&save(&timings)).unwrap(), timings);
+        assert!(load("5\t2\tinput.txt\n").is_err());
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let old = vec![timing(5, 2, "input.txt", 4)];
+        let table = table(&[timing(5, 2, "input.txt", 2), timing(6, 1, "input.txt", 1)], Some(&old));
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[0].ends_with("baseline   change"));
+        assert!(lines[1].ends_with("-50.0%"));
+        assert!(lines[2].ends_with("1.0ms"));
+        assert!(lines[3].contains("total"));
+    }
+}
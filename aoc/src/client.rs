@@ -0,0 +1,223 @@
+use std::fmt;
+use std::io;
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+
+use crate::store::{Store, Submission, Verdict};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2023;
+const USER_AGENT: &str = "aoc2023 runner (inputs are cached locally)";
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// `AOC_SESSION` isn't set and the answer had to come from the site.
+    NoSession,
+    Io(io::Error),
+    Http(ureq::Error),
+    Status(u16),
+    /// The site wants us to wait before submitting again.
+    TooSoon(Option<Duration>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    UnknownResponse,
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(f, "no session token, set AOC_SESSION"),
+            ClientError::Io(e) => write!(f, "{}", e),
+            ClientError::Http(e) => write!(f, "request failed: {}", e),
+            ClientError::Status(400) => write!(f, "site answered 400, the session token is probably stale"),
+            ClientError::Status(404) => write!(f, "site answered 404, that day isn't unlocked yet"),
+            ClientError::Status(status) => write!(f, "site answered {}", status),
+            ClientError::TooSoon(Some(wait)) => write!(f, "answered too recently, wait {}s", wait.as_secs()),
+            ClientError::TooSoon(None) => write!(f, "answered too recently"),
+            ClientError::WrongLevel => write!(f, "that part is already solved or still locked"),
+            ClientError::UnknownResponse => write!(f, "couldn't make sense of the response"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+/// Talks to the site, going through the [`Store`] first so nothing is asked
+/// twice, and keeping at least `min_interval` between requests.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    store: Store,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(store: Store, session: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Client { agent, base_url: BASE_URL.to_owned(), session, store, min_interval: Duration::from_secs(5) }
+    }
+
+    /// Store from `AOC_CACHE_DIR` or `~/.cache/aoc2023`, session from `AOC_SESSION`.
+    pub fn from_env() -> io::Result<Self> {
+        let session = std::env::var("AOC_SESSION").ok().map(|s| s.trim().to_owned()).filter(|s| !s.is_empty());
+        Ok(Client::new(Store::open_default()?, session))
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    fn session(&self) -> Result<&str, ClientError> {
+        self.session.as_deref().ok_or(ClientError::NoSession)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then
+    /// marks this one.
+    fn wait_turn(&self) -> io::Result<()> {
+        if let Some(last) = self.store.last_request() {
+            let since = SystemTime::now().duration_since(last).unwrap_or_default();
+            if since < self.min_interval {
+                debug!("waiting {:?} before the next request", self.min_interval - since);
+                std::thread::sleep(self.min_interval - since);
+            }
+        }
+        self.store.mark_request()
+    }
+
+    /// The day's input, downloaded only if the store doesn't have it yet.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        if let Some(input) = self.store.input(day, "input")? {
+            debug!("day {} input from {}", day, self.store.input_path(day, "input").display());
+            return Ok(input);
+        }
+        let session = self.session()?;
+        self.wait_turn()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        debug!("GET {}", url);
+        let mut response = self.agent.get(&url).header("Cookie", &format!("session={}", session)).call()?;
+        if response.status() != 200 {
+            return Err(ClientError::Status(response.status().as_u16()));
+        }
+        let input = response.body_mut().read_to_string()?;
+        self.store.save_input(day, "input", &input)?;
+        Ok(input)
+    }
+
+    /// Submits an answer, unless an earlier submission already tells us
+    /// what the site would say.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, ClientError> {
+        if let Some(verdict) = self.store.known_verdict(day, part, answer)? {
+            debug!("day {} part {} answer {} already known to be {}", day, part, answer, verdict);
+            return Ok(verdict);
+        }
+        let session = self.session()?;
+        self.wait_turn()?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        debug!("POST {}", url);
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", session))
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+        if response.status() != 200 {
+            return Err(ClientError::Status(response.status().as_u16()));
+        }
+        let verdict = parse_verdict(&response.body_mut().read_to_string()?)?;
+        self.store.record_submission(day, &Submission { part, answer: answer.to_owned(), verdict })?;
+        Ok(verdict)
+    }
+}
+
+/// Reads the verdict out of the answer page.
+pub fn parse_verdict(page: &str) -> Result<Verdict, ClientError> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if page.contains("answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Err(ClientError::TooSoon(wait_time(page)))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err(ClientError::WrongLevel)
+    } else {
+        Err(ClientError::UnknownResponse)
+    }
+}
+
+/// "You have 1m 5s left to wait."
+fn wait_time(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for part in page[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(parse_verdict(&page("That's the right answer! You are one gold star closer.")).unwrap(), Verdict::Correct);
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer; your answer is too high.")).unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(parse_verdict(&page("That's not the right answer; your answer is too low.")).unwrap(), Verdict::TooLow);
+        assert_eq!(parse_verdict(&page("That's not the right answer.")).unwrap(), Verdict::Wrong);
+        assert!(matches!(
+            parse_verdict(&page("You gave an answer too recently. You have 1m 5s left to wait.")),
+            Err(ClientError::TooSoon(Some(d))) if d == Duration::from_secs(65)
+        ));
+        assert!(matches!(
+            parse_verdict(&page("You don't seem to be solving the right level.")),
+            Err(ClientError::WrongLevel)
+        ));
+        assert!(matches!(parse_verdict("<html></html>"), Err(ClientError::UnknownResponse)));
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod bench;
+pub mod client;
+pub mod output;
+pub mod store;
+pub mod verify;
+
+/// Path of a file inside `day_N/`, e.g. the default `input.txt`.
+pub fn day_file(day: u8, file: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day_{}", day)).join(file)
+}
+
+pub fn solve(day: u8, part: u8, input: &str) -> Result<Option<Answer>, ParseError> {
+    match day {
+        1 => day_1::Day1::solve(part, input),
+        2 => day_2::Day2::solve(part, input),
+        3 => day_3::Day3::solve(part, input),
+        4 => day_4::Day4::solve(part, input),
+        5 => day_5::Day5::solve(part, input),
+        6 => day_6::Day6::solve(part, input),
+        7 => day_7::Day7::solve(part, input),
+        8 => day_8::Day8::solve(part, input),
+        9 => day_9::Day9::solve(part, input),
+        10 => day_10::Day10::solve(part, input),
+        11 => day_11::Day11::solve(part, input),
+        12 => day_12::Day12::solve(part, input),
+        13 => day_13::Day13::solve(part, input),
+        14 => day_14::Day14::solve(part, input),
+        15 => day_15::Day15::solve(part, input),
+        16 => day_16::Day16::solve(part, input),
+        17 => day_17::Day17::solve(part, input),
+        18 => day_18::Day18::solve(part, input),
+        19 => day_19::Day19::solve(part, input),
+        20 => day_20::Day20::solve(part, input),
+        21 => day_21::Day21::solve(part, input),
+        22 => day_22::Day22::solve(part, input),
+        23 => day_23::Day23::solve(part, input),
+        24 => day_24::Day24::solve(part, input),
+        25 => day_25::Day25::solve(part, input),
+        _ => Ok(None),
+    }
+}
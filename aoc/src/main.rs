@@ -0,0 +1,233 @@
+use std::io::Read;
+use std::process::ExitCode;
+
+use aoc::client::Client;
+use aoc::output::Report;
+use aoc::store::{Store, Verdict};
+use aoc::{bench, verify};
+use aoc_common::Answer;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    /// Log solver progress to stderr, -vv for everything. RUST_LOG works too
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day/part against an input
+    Run {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long)]
+        part: u8,
+        /// Path to the input file, or `-` for stdin. Defaults to day_N/input.txt,
+        /// then the downloaded input
+        #[arg(short, long)]
+        input: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check solutions against the answers listed in each day's answers.toml
+    Verify {
+        /// Only check this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Also run inputs marked as slow
+        #[arg(long)]
+        slow: bool,
+    },
+    /// Time every manifest entry and print a summary table
+    Bench {
+        /// Only time this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Also time inputs marked as slow
+        #[arg(long)]
+        slow: bool,
+        /// Runs per entry, the median is reported
+        #[arg(short, long, default_value_t = 5)]
+        runs: usize,
+        /// Write the timings to this file
+        #[arg(long)]
+        save: Option<String>,
+        /// Compare against timings written earlier with --save
+        #[arg(long)]
+        baseline: Option<String>,
+    },
+    /// Download a day's input into the cache, needs AOC_SESSION
+    Fetch {
+        #[arg(short, long)]
+        day: u8,
+    },
+    /// Submit an answer, solving the downloaded input if none is given
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long)]
+        part: u8,
+        answer: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Just the answer
+    Text,
+    /// One JSON object with the answer, timing and solver stats
+    Json,
+}
+
+/// The input and where it came from, for the report.
+fn read_input(day: u8, input: Option<&str>) -> std::io::Result<(String, String)> {
+    match input {
+        Some("-") => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            Ok(("<stdin>".to_owned(), buf))
+        }
+        Some(path) => Ok((path.to_owned(), std::fs::read_to_string(path)?)),
+        None => match std::fs::read_to_string(aoc::day_file(day, "input.txt")) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let store = Store::open_default()?;
+                let text = store.input(day, "input")?.ok_or(e)?;
+                Ok((store.input_path(day, "input").display().to_string(), text))
+            }
+            other => Ok((format!("day_{}/input.txt", day), other?)),
+        },
+    }
+}
+
+fn run(day: u8, part: u8, input: Option<String>, format: Format) -> ExitCode {
+    let (file, input) = match read_input(day, input.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = Report::solve(day, part, &file, &input);
+    match format {
+        Format::Text => match &report.result {
+            Ok(answer) => println!("{}", answer),
+            Err(e) => eprintln!("error: {}", e),
+        },
+        Format::Json => println!("{}", report.to_json()),
+    }
+    if report.result.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn days(day: Option<u8>) -> std::ops::RangeInclusive<u8> {
+    match day {
+        Some(day) => day..=day,
+        None => 1..=25,
+    }
+}
+
+fn verify(day: Option<u8>, slow: bool) -> ExitCode {
+    let results = match verify::run(days(day), slow) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    for result in &results {
+        println!("{}", result);
+    }
+    let failed = results.iter().filter(|r| !r.passed()).count();
+    println!("{} passed, {} failed", results.len() - failed, failed);
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(day: Option<u8>, slow: bool, runs: usize, save: Option<String>, baseline: Option<String>) -> Result<(), String> {
+    let baseline = match baseline {
+        Some(path) => {
+            let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+            Some(bench::load(&text).map_err(|e| format!("{}: {}", path, e))?)
+        }
+        None => None,
+    };
+    let timings = verify::expectations(days(day), slow)?
+        .iter()
+        .map(|e| bench::measure(e, runs))
+        .collect::<Result<Vec<_>, _>>()?;
+    print!("{}", bench::table(&timings, baseline.as_deref()));
+    if let Some(path) = save {
+        std::fs::write(&path, bench::save(&timings)).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<(), String> {
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    client.input(day).map_err(|e| e.to_string())?;
+    println!("{}", client.store().input_path(day, "input").display());
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, answer: Option<String>) -> Result<Verdict, String> {
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = client.input(day).map_err(|e| e.to_string())?;
+            match aoc::solve(day, part, &input).map_err(|e| e.to_string())? {
+                None | Some(Answer::Unsolved) => return Err(format!("day {} part {} isn't solved", day, part)),
+                Some(answer) => answer.to_string(),
+            }
+        }
+    };
+    println!("submitting {}", answer);
+    client.submit(day, part, &answer).map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_common::init_tracing(cli.verbose);
+    match cli.command {
+        Command::Run { day, part, input, format } => run(day, part, input, format),
+        Command::Verify { day, slow } => verify(day, slow),
+        Command::Bench { day, slow, runs, save, baseline } => match bench(day, slow, runs, save, baseline) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { day } => match fetch(day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit { day, part, answer } => match submit(day, part, answer) {
+            Ok(verdict) => {
+                println!("{}", verdict);
+                if verdict == Verdict::Correct {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use aoc_common::Answer;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+/// Everything `aoc run` knows about one solve, printed as text or JSON.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub input_path: String,
+    pub input_sha256: String,
+    pub result: Result<Answer, String>,
+    pub elapsed: Duration,
+    pub stats: BTreeMap<&'static str, Answer>,
+}
+
+impl Report {
+    /// Times `aoc::solve` and collects whatever stats the solver recorded.
+    pub fn solve(day: u8, part: u8, input_path: &str, input: &str) -> Self {
+        aoc_common::stats::take();
+        let start = std::time::Instant::now();
+        let result = crate::solve(day, part, input);
+        let elapsed = start.elapsed();
+        let result = match result {
+            Ok(Some(answer)) => Ok(answer),
+            Ok(None) => Err(format!("no solution for day {} part {}", day, part)),
+            Err(e) => Err(e.with_file(input_path).to_string()),
+        };
+        Report {
+            day,
+            part,
+            input_path: input_path.to_owned(),
+            input_sha256: sha256(input),
+            result,
+            elapsed,
+            stats: aoc_common::stats::take(),
+        }
+    }
+
+    /// Same shape for every day:
+    ///
+    /// ```json
+    /// {"day": 14, "part": 2, "input": {"path": "...", "sha256": "..."},
+    ///  "answer": "88371", "error": null, "elapsed_ns": 770512,
+    ///  "stats": {"cycle_length": 7, "cycle_start": 3}}
+    /// ```
+    ///
+    /// `answer` is always a string so large answers survive JSON parsers that
+    /// only have doubles. Exactly one of `answer`/`error` is null.
+    pub fn to_json(&self) -> Value {
+        let (answer, error) = match &self.result {
+            Ok(answer) => (Value::String(answer.to_string()), Value::Null),
+            Err(e) => (Value::Null, Value::String(e.clone())),
+        };
+        let stats = self.stats.iter().map(|(k, v)| (k.to_string(), stat_json(v))).collect::<serde_json::Map<_, _>>();
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "input": { "path": self.input_path, "sha256": self.input_sha256 },
+            "answer": answer,
+            "error": error,
+            "elapsed_ns": self.elapsed.as_nanos() as u64,
+            "stats": stats,
+        })
+    }
+}
+
+fn stat_json(value: &Answer) -> Value {
+    match value {
+        Answer::Signed(n) => i64::try_from(*n).map_or_else(|_| n.to_string().into(), Value::from),
+        Answer::Unsigned(n) => u64::try_from(*n).map_or_else(|_| n.to_string().into(), Value::from),
+        Answer::Text(s) => Value::String(s.clone()),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+fn sha256(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema() {
+        let report = Report::solve(6, 1, "day_6/input2.txt", "Time:      7  15   30\nDistance:  9  40  200\n");
+        let json = report.to_json();
+        assert_eq!(json["day"], 6);
+        assert_eq!(json["answer"], "288");
+        assert_eq!(json["error"], Value::Null);
+        assert_eq!(json["input"]["sha256"].as_str().unwrap().len(), 64);
+        assert!(json["stats"].as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn json_error() {
+        let json = Report::solve(4, 1, "x.txt", "Card 1: 1 2 | x\n").to_json();
+        assert_eq!(json["answer"], Value::Null);
+        assert!(json["error"].as_str().unwrap().starts_with("x.txt:1:"));
+    }
+
+    #[test]
+    fn big_stats_become_strings() {
+        assert_eq!(stat_json(&Answer::Unsigned(5)), json!(5));
+        assert_eq!(stat_json(&Answer::Unsigned(u128::MAX)), json!(u128::MAX.to_string()));
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong].into_iter().find(|v| v.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Downloaded inputs and everything ever submitted, one directory per day:
+///
+/// ```text
+/// <root>/5/input.txt
+/// <root>/5/submissions.tsv
+/// <root>/last_request
+/// ```
+#[derive(Debug, Clone)]
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Store { root: root.into() }
+    }
+
+    /// `$AOC_CACHE_DIR` if set, otherwise `~/.cache/aoc2023`.
+    pub fn open_default() -> io::Result<Self> {
+        if let Some(dir) = std::env::var_os("AOC_CACHE_DIR") {
+            return Ok(Store::new(dir));
+        }
+        let cache = dirs::cache_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no cache directory, set AOC_CACHE_DIR"))?;
+        Ok(Store::new(cache.join("aoc2023")))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn day_dir(&self, day: u8) -> PathBuf {
+        self.root.join(day.to_string())
+    }
+
+    pub fn input_path(&self, day: u8, name: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{}.txt", name))
+    }
+
+    /// The stored input, `None` if it was never downloaded.
+    pub fn input(&self, day: u8, name: &str) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(self.input_path(day, name)) {
+            Ok(text) => Ok(Some(text)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save_input(&self, day: u8, name: &str, text: &str) -> io::Result<()> {
+        std::fs::create_dir_all(self.day_dir(day))?;
+        std::fs::write(self.input_path(day, name), text)
+    }
+
+    pub fn submissions(&self, day: u8) -> io::Result<Vec<Submission>> {
+        let text = match std::fs::read_to_string(self.day_dir(day).join("submissions.tsv")) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+        text.lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                let mut fields = l.splitn(3, '\t');
+                let part = fields.next().and_then(|p| p.parse().ok());
+                let verdict = fields.next().and_then(Verdict::from_name);
+                match (part, verdict, fields.next()) {
+                    (Some(part), Some(verdict), Some(answer)) => Ok(Submission { part, answer: answer.to_owned(), verdict }),
+                    _ => Err(io::Error::new(io::ErrorKind::InvalidData, format!("bad submissions line '{}'", l))),
+                }
+            })
+            .collect()
+    }
+
+    pub fn record_submission(&self, day: u8, submission: &Submission) -> io::Result<()> {
+        std::fs::create_dir_all(self.day_dir(day))?;
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(self.day_dir(day).join("submissions.tsv"))?;
+        writeln!(file, "{}\t{}\t{}", submission.part, submission.verdict.name(), submission.answer)
+    }
+
+    /// What submitting `answer` would say, if earlier submissions already
+    /// tell us. Uses the too high/too low hints for numeric answers.
+    pub fn known_verdict(&self, day: u8, part: u8, answer: &str) -> io::Result<Option<Verdict>> {
+        let earlier = self.submissions(day)?.into_iter().filter(|s| s.part == part).collect::<Vec<_>>();
+        if let Some(s) = earlier.iter().find(|s| s.answer == answer) {
+            return Ok(Some(s.verdict));
+        }
+        if earlier.iter().any(|s| s.verdict == Verdict::Correct) {
+            return Ok(Some(Verdict::Wrong));
+        }
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(None);
+        };
+        for s in &earlier {
+            match (s.verdict, s.answer.parse::<i128>()) {
+                (Verdict::TooHigh, Ok(high)) if value >= high => return Ok(Some(Verdict::TooHigh)),
+                (Verdict::TooLow, Ok(low)) if value <= low => return Ok(Some(Verdict::TooLow)),
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// When the last request to the site went out, from any process.
+    pub fn last_request(&self) -> Option<SystemTime> {
+        let millis = std::fs::read_to_string(self.root.join("last_request")).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    pub fn mark_request(&self) -> io::Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        std::fs::write(self.root.join("last_request"), millis.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission { part, answer: answer.to_owned(), verdict }
+    }
+
+    #[test]
+    fn inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::new(dir.path());
+        assert_eq!(store.input(5, "input").unwrap(), None);
+        store.save_input(5, "input", "seeds: 1 2\n").unwrap();
+        assert_eq!(store.input(5, "input").unwrap().as_deref(), Some("seeds: 1 2\n"));
+        assert_eq!(store.input_path(5, "input"), dir.path().join("5").join("input.txt"));
+    }
+
+    #[test]
+    fn remembers_submissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::new(dir.path());
+        store.record_submission(1, &submission(1, "500", Verdict::TooHigh)).unwrap();
+        store.record_submission(1, &submission(1, "100", Verdict::TooLow)).unwrap();
+        store.record_submission(1, &submission(1, "abc", Verdict::Wrong)).unwrap();
+        assert_eq!(store.submissions(1).unwrap().len(), 3);
+
+        assert_eq!(store.known_verdict(1, 1, "abc").unwrap(), Some(Verdict::Wrong));
+        assert_eq!(store.known_verdict(1, 1, "600").unwrap(), Some(Verdict::TooHigh));
+        assert_eq!(store.known_verdict(1, 1, "99").unwrap(), Some(Verdict::TooLow));
+        assert_eq!(store.known_verdict(1, 1, "300").unwrap(), None);
+        assert_eq!(store.known_verdict(1, 2, "600").unwrap(), None);
+
+        store.record_submission(1, &submission(1, "300", Verdict::Correct)).unwrap();
+        assert_eq!(store.known_verdict(1, 1, "300").unwrap(), Some(Verdict::Correct));
+        assert_eq!(store.known_verdict(1, 1, "301").unwrap(), Some(Verdict::Wrong));
+    }
+
+    #[test]
+    fn last_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::new(dir.path());
+        assert_eq!(store.last_request(), None);
+        store.mark_request().unwrap();
+        assert!(store.last_request().unwrap().elapsed().unwrap() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use toml::{Table, Value};
+
+/// One expected answer from a day's `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub day: u8,
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+    pub slow: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { got: String },
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub expectation: Expectation,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Reads `day_N/answers.toml`. A missing manifest just means no expectations.
+///
+/// ```toml
+/// ["input.txt"]
+/// part1 = 54953
+/// part2 = "53868"
+/// slow = true      # optional, skipped unless asked for
+/// ```
+pub fn load_manifest(day: u8) -> Result<Vec<Expectation>, String> {
+    let path = crate::day_file(day, "answers.toml");
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+    parse_manifest(day, &text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+pub fn parse_manifest(day: u8, text: &str) -> Result<Vec<Expectation>, String> {
+    let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+    let mut ret = vec![];
+    for (file, entry) in &table {
+        let entry = entry.as_table().ok_or_else(|| format!("[\"{}\"] should be a table", file))?;
+        let slow = match entry.get("slow") {
+            None => false,
+            Some(Value::Boolean(b)) => *b,
+            Some(_) => return Err(format!("[\"{}\"] slow should be true or false", file)),
+        };
+        for (key, value) in entry {
+            let part = match key.as_str() {
+                "part1" => 1,
+                "part2" => 2,
+                "slow" => continue,
+                _ => return Err(format!("[\"{}\"] unknown key '{}'", file, key)),
+            };
+            let expected = match value {
+                Value::Integer(n) => n.to_string(),
+                Value::String(s) => s.clone(),
+                _ => return Err(format!("[\"{}\"] {} should be a number or string", file, key)),
+            };
+            ret.push(Expectation { day, file: file.clone(), part, expected, slow });
+        }
+    }
+    Ok(ret)
+}
+
+pub fn check(expectation: &Expectation) -> CheckResult {
+    let start = Instant::now();
+    let outcome = match std::fs::read_to_string(crate::day_file(expectation.day, &expectation.file)) {
+        Err(e) => Outcome::Error(e.to_string()),
+        Ok(input) => match crate::solve(expectation.day, expectation.part, &input) {
+            Ok(Some(answer)) if answer.to_string() == expectation.expected => Outcome::Pass,
+            Ok(Some(answer)) => Outcome::Fail { got: answer.to_string() },
+            Ok(None) => Outcome::Error("no solution for this part".to_owned()),
+            Err(e) => Outcome::Error(e.with_file(&expectation.file).to_string()),
+        },
+    };
+    CheckResult {
+        expectation: expectation.clone(),
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Every manifest entry for `days`. Slow entries are left out unless `slow` is set.
+pub fn expectations(days: impl IntoIterator<Item = u8>, slow: bool) -> Result<Vec<Expectation>, String> {
+    let mut ret = vec![];
+    for day in days {
+        ret.extend(load_manifest(day)?.into_iter().filter(|e| slow || !e.slow));
+    }
+    Ok(ret)
+}
+
+/// Checks every manifest entry for `days`. Slow entries only run if `slow` is set.
+pub fn run(days: impl IntoIterator<Item = u8>, slow: bool) -> Result<Vec<CheckResult>, String> {
+    Ok(expectations(days, slow)?.iter().map(check).collect())
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let e = &self.expectation;
+        let status = if self.passed() { "PASS" } else { "FAIL" };
+        write!(f, "{} day {:>2} part {} {:<20} ", status, e.day, e.part, e.file)?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "{}", e.expected)?,
+            Outcome::Fail { got } => write!(f, "expected {}, got {}", e.expected, got)?,
+            Outcome::Error(msg) => write!(f, "{}", msg)?,
+        }
+        write!(f, " ({:.1?})", self.elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let text = "[\"input.txt\"]\npart1 = 12\npart2 = \"340282366920938463463374607431768211455\"\nslow = true\n\n[\"input2.txt\"]\npart2 = 3\n";
+        let exp = parse_manifest(4, text).unwrap();
+        assert_eq!(exp.len(), 3);
+        assert_eq!(exp[0], Expectation { day: 4, file: "input.txt".to_owned(), part: 1, expected: "12".to_owned(), slow: true });
+        assert_eq!(exp[1].expected, u128::MAX.to_string());
+        assert!(!exp[2].slow);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse_manifest(1, "[\"input.txt\"]\npart3 = 1\n").is_err());
+        assert!(parse_manifest(1, "[\"input.txt\"]\npart1 = 1.5\n").is_err());
+        assert!(parse_manifest(1, "part1 = 1\n").is_err());
+    }
+}
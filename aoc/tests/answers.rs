@@ -0,0 +1,22 @@
+use aoc::verify;
+
+fn check_all(slow: bool) {
+    let results = verify::run(1..=25, slow).unwrap();
+    let failures = results
+        .iter()
+        .filter(|r| !r.passed() && r.expectation.slow == slow)
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn answers_match_manifest() {
+    check_all(false);
+}
+
+#[test]
+#[ignore = "takes minutes, run with --ignored"]
+fn slow_answers_match_manifest() {
+    check_all(true);
+}
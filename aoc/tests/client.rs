@@ -0,0 +1,146 @@
+//! Runs the client against a local stand-in for the site, nothing here
+//! touches the network.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::client::{Client, ClientError};
+use aoc::store::{Store, Verdict};
+
+const ANSWER: &str = "142";
+
+/// Requests seen by the server, as `METHOD path body`, with when they came in.
+type Log = Arc<Mutex<Vec<(String, Instant)>>>;
+
+struct MockSite {
+    url: String,
+    log: Log,
+}
+
+impl MockSite {
+    fn start() -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let log = Log::default();
+        let seen = log.clone();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let arrived = Instant::now();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let line = format!("{} {} {}", request.method(), request.url(), body);
+                seen.lock().unwrap().push((line, arrived));
+                let authorized =
+                    request.headers().iter().any(|h| h.field.equiv("Cookie") && h.value.as_str() == "session=secret");
+                let (status, text) = match (authorized, request.url()) {
+                    (false, _) => (400, "Puzzle inputs differ by user.".to_owned()),
+                    (true, "/2023/day/1/input") => (200, "1abc2\npqr3stu8vwx\n".to_owned()),
+                    (true, "/2023/day/1/answer") => (200, answer_page(&body)),
+                    _ => (404, "404 Not Found".to_owned()),
+                };
+                request.respond(tiny_http::Response::from_string(text).with_status_code(status)).unwrap();
+            }
+        });
+        MockSite { url, log }
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.log.lock().unwrap().iter().map(|(line, _)| line.clone()).collect()
+    }
+
+    fn client(&self, store: Store) -> Client {
+        Client::new(store, Some("secret".to_owned())).with_base_url(&self.url).with_min_interval(Duration::ZERO)
+    }
+}
+
+fn answer_page(form: &str) -> String {
+    let answer = form.split('&').find_map(|kv| kv.strip_prefix("answer=")).unwrap_or("");
+    let text = match answer.parse::<u64>() {
+        Ok(n) if answer == ANSWER => format!("That's the right answer! {}", n),
+        Ok(n) if n > 142 => "That's not the right answer; your answer is too high.".to_owned(),
+        Ok(_) => "That's not the right answer; your answer is too low.".to_owned(),
+        Err(_) => "That's not the right answer.".to_owned(),
+    };
+    format!("<main><article><p>{}</p></article></main>", text)
+}
+
+#[test]
+fn input_is_downloaded_once() {
+    let site = MockSite::start();
+    let dir = tempfile::tempdir().unwrap();
+    let client = site.client(Store::new(dir.path()));
+    assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(site.requests(), vec!["GET /2023/day/1/input "]);
+    assert!(dir.path().join("1").join("input.txt").exists());
+
+    assert!(matches!(client.input(2), Err(ClientError::Status(404))));
+}
+
+#[test]
+fn cached_input_needs_no_session() {
+    let site = MockSite::start();
+    let dir = tempfile::tempdir().unwrap();
+    let store = Store::new(dir.path());
+    store.save_input(1, "input", "cached\n").unwrap();
+    let client = Client::new(store, None).with_base_url(&site.url);
+    assert_eq!(client.input(1).unwrap(), "cached\n");
+    assert!(matches!(client.input(3), Err(ClientError::NoSession)));
+    assert!(site.requests().is_empty());
+}
+
+#[test]
+fn bad_session() {
+    let site = MockSite::start();
+    let dir = tempfile::tempdir().unwrap();
+    let client = Client::new(Store::new(dir.path()), Some("stale".to_owned())).with_base_url(&site.url);
+    assert!(matches!(client.input(1), Err(ClientError::Status(400))));
+}
+
+#[test]
+fn wrong_answers_are_remembered() {
+    let site = MockSite::start();
+    let dir = tempfile::tempdir().unwrap();
+    let client = site.client(Store::new(dir.path()));
+    assert_eq!(client.submit(1, 1, "200").unwrap(), Verdict::TooHigh);
+    assert_eq!(client.submit(1, 1, "200").unwrap(), Verdict::TooHigh);
+    // Anything above a known too high answer never gets sent.
+    assert_eq!(client.submit(1, 1, "300").unwrap(), Verdict::TooHigh);
+    assert_eq!(client.submit(1, 1, "nope").unwrap(), Verdict::Wrong);
+    assert_eq!(client.submit(1, 1, "nope").unwrap(), Verdict::Wrong);
+    assert_eq!(client.submit(1, 1, "142").unwrap(), Verdict::Correct);
+    assert_eq!(client.submit(1, 1, "141").unwrap(), Verdict::Wrong);
+    assert_eq!(
+        site.requests(),
+        vec![
+            "POST /2023/day/1/answer level=1&answer=200",
+            "POST /2023/day/1/answer level=1&answer=nope",
+            "POST /2023/day/1/answer level=1&answer=142",
+        ]
+    );
+
+    // Another client on the same store knows all of it too.
+    let again = site.client(Store::new(dir.path()));
+    assert_eq!(again.submit(1, 1, "142").unwrap(), Verdict::Correct);
+    assert_eq!(site.requests().len(), 3);
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let site = MockSite::start();
+    let dir = tempfile::tempdir().unwrap();
+    let interval = Duration::from_millis(300);
+    let client = site.client(Store::new(dir.path())).with_min_interval(interval);
+    client.submit(1, 1, "1").unwrap();
+    client.submit(1, 1, "500").unwrap();
+    // A second client sharing the store waits as well.
+    site.client(Store::new(dir.path())).with_min_interval(interval).input(1).unwrap();
+    let times = site.log.lock().unwrap().iter().map(|(_, t)| *t).collect::<Vec<_>>();
+    assert_eq!(times.len(), 3);
+    for pair in times.windows(2) {
+        // The client waits from before it sends, the server times arrivals,
+        // so allow for requests taking different times to get there.
+        assert!(pair[1] - pair[0] >= interval - Duration::from_millis(30), "{:?}", pair[1] - pair[0]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+/// Error for malformed puzzle input.
+///
+/// Parsers usually only see a piece of a line, so they can't know where they
+/// are in the file. Instead the error remembers where `found` lives in memory,
+/// and `locate` turns that into a line/column once the whole input is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based, 0 if unknown
+    pub line: usize,
+    /// 1-based, 0 if unknown
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    addr: Option<usize>,
+    eof: bool,
+}
+
+impl ParseError {
+    /// `found` should be a slice of the input being parsed (an empty slice
+    /// for "nothing there"), otherwise `locate` can't place it.
+    pub fn new(expected: impl Into<String>, found: &str) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: found.to_owned(),
+            addr: Some(found.as_ptr() as usize),
+            eof: false,
+        }
+    }
+
+    /// For when `input` ran out before `expected` showed up. Located at the
+    /// end of `input` straight away.
+    pub fn end_of_input(expected: impl Into<String>, input: &str) -> Self {
+        let mut e = Self::new(expected, &input[input.len()..]).locate(input);
+        e.eof = true;
+        e
+    }
+
+    /// For when the position is already known.
+    pub fn at(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+            addr: None,
+            eof: false,
+        }
+    }
+
+    /// Works out line and column relative to `input`. Does nothing if the
+    /// error is already located or `found` isn't part of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some(addr) = self.addr else {
+            return self;
+        };
+        let start = input.as_ptr() as usize;
+        if addr < start || addr > start + input.len() {
+            return self;
+        }
+        let offset = addr - start;
+        let before = &input[..offset];
+        self.line = before.matches('\n').count() + 1;
+        self.column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        self.addr = None;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), 0) => write!(f, "{}: ", file)?,
+            (Some(file), line) => write!(f, "{}:{}:{}: ", file, line, self.column)?,
+            (None, 0) => {}
+            (None, line) => write!(f, "line {}, column {}: ", line, self.column)?,
+        }
+        write!(f, "expected {}, found ", self.expected)?;
+        if self.eof {
+            write!(f, "end of input")
+        } else if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "'{}'", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_in_input() {
+        let input = "Game 1: 3 blue\nGame 2: 4 purple\n";
+        let bad = &input[input.find("purple").unwrap()..][..6];
+        let e = ParseError::new("colour", bad).locate(input);
+        assert_eq!((e.line, e.column), (2, 11));
+        assert_eq!(e.with_file("input.txt").to_string(), "input.txt:2:11: expected colour, found 'purple'");
+    }
+
+    #[test]
+    fn foreign_slice_stays_unlocated() {
+        let e = ParseError::new("number", "x").locate("abc");
+        assert_eq!((e.line, e.column), (0, 0));
+        assert_eq!(e.to_string(), "expected number, found 'x'");
+    }
+
+    #[test]
+    fn end_of_line() {
+        let input = "1 2";
+        let e = ParseError::new("number", &input[3..]).locate(input);
+        assert_eq!(e.to_string(), "line 1, column 4: expected number, found end of line");
+    }
+
+    #[test]
+    fn end_of_input() {
+        let e = ParseError::end_of_input("a seed", "\n\n");
+        assert_eq!(e.to_string(), "line 3, column 1: expected a seed, found end of input");
+        assert_eq!(ParseError::end_of_input("a row", "").line, 1);
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+use std::process::ExitCode;
+
+use tracing_subscriber::EnvFilter;
+
+mod error;
+pub mod parse;
+pub mod stats;
+
+pub use error::ParseError;
+pub use parse::Scanner;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as $wide)
+            }
+        })*
+    };
+}
+
+impl_from!(Signed, i128, i32, i64, i128, isize);
+impl_from!(Unsigned, u128, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Stand-in for a part that has no solution (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl From<Unsolved> for Answer {
+    fn from(_: Unsolved) -> Self {
+        Answer::Unsolved
+    }
+}
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+pub trait Solution {
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn solve_part1(input: &str) -> Result<Self::Part1, ParseError>;
+    fn solve_part2(input: &str) -> Result<Self::Part2, ParseError>;
+
+    fn solve(part: u8, input: &str) -> Result<Option<Answer>, ParseError> {
+        let answer = match part {
+            1 => Self::solve_part1(input)?.into(),
+            2 => Self::solve_part2(input)?.into(),
+            _ => return Ok(None),
+        };
+        match answer {
+            Answer::Unsolved => Ok(None),
+            answer => Ok(Some(answer)),
+        }
+    }
+}
+
+/// Prints `label: answer` for the per-day binaries, or a diagnostic pointing
+/// into `file` if the input didn't parse.
+pub fn report<T: fmt::Display>(label: &str, file: &str, result: Result<T, ParseError>) -> ExitCode {
+    match result {
+        Ok(answer) => {
+            println!("{}: {}", label, answer);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(file));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Sends solver logs to stderr. `-v` gives 1 (debug), `-vv` 2 (trace).
+/// Without any, `RUST_LOG` decides, e.g. `RUST_LOG=day_7=trace`.
+pub fn init_tracing(verbosity: u8) {
+    let filter = match verbosity {
+        0 => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        1 => EnvFilter::new("debug"),
+        _ => EnvFilter::new("trace"),
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        type Part1 = usize;
+        type Part2 = Unsolved;
+
+        fn solve_part1(input: &str) -> Result<usize, ParseError> {
+            input.lines().map(|l| l.parse::<usize>().map_err(|_| ParseError::new("number", l))).sum()
+        }
+
+        fn solve_part2(_input: &str) -> Result<Unsolved, ParseError> {
+            Ok(Unsolved)
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        assert_eq!(Dummy::solve(1, "1\n2\n"), Ok(Some(Answer::Unsigned(3))));
+        assert_eq!(Dummy::solve(2, "1\n2\n"), Ok(None));
+        assert_eq!(Dummy::solve(3, "1\n2\n"), Ok(None));
+        assert_eq!(Dummy::solve(1, "1\nb\n").unwrap_err().expected, "number");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+}
@@ -0,0 +1,240 @@
+//! Tiny zero-copy scanner the day parsers are written with.
+//!
+//! Nothing is compiled up front, so constructing a `Scanner` per line is as
+//! cheap as slicing. Every error points at a slice of the input, which means
+//! `ParseError::locate` can turn it into a line/column afterwards.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Scanner { rest: input }
+    }
+
+    /// Whatever hasn't been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// The next whitespace-delimited token, or the next character if that is
+    /// whitespace. Empty at the end of input.
+    fn token(&self) -> &'a str {
+        let end = self.rest.find(char::is_whitespace).unwrap_or(self.rest.len());
+        if end == 0 {
+            &self.rest[..self.peek().map_or(0, char::len_utf8)]
+        } else {
+            &self.rest[..end]
+        }
+    }
+
+    /// Error pointing at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(expected, self.token())
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes `tag` if it's next.
+    pub fn eat(&mut self, tag: &str) -> bool {
+        if self.rest.starts_with(tag) {
+            self.advance(tag.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", tag)))
+        }
+    }
+
+    /// Skips spaces and tabs, but not newlines.
+    pub fn spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    pub fn take_while(&mut self, mut f: impl FnMut(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !f(c)).unwrap_or(self.rest.len());
+        self.advance(end)
+    }
+
+    /// Like `take_while`, but at least one character has to match.
+    pub fn take_while1(&mut self, expected: &str, f: impl FnMut(char) -> bool) -> Result<&'a str, ParseError> {
+        let taken = self.take_while(f);
+        if taken.is_empty() {
+            Err(self.error(expected))
+        } else {
+            Ok(taken)
+        }
+    }
+
+    /// A run of ASCII letters and digits.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.take_while1("word", |c| c.is_ascii_alphanumeric())
+    }
+
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let digits = self.take_while1("number", |c| c.is_ascii_digit())?;
+        digits.parse().map_err(|_| ParseError::new("smaller number", digits))
+    }
+
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+        self.eat("-");
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            *self = start;
+            return Err(self.error("number"));
+        }
+        self.take_while(|c| c.is_ascii_digit());
+        let number = &start.rest[..start.rest.len() - self.rest.len()];
+        number.parse().map_err(|_| ParseError::new("smaller number", number))
+    }
+
+    /// Whichever of `options` comes next, longest match wins.
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        let found = options
+            .iter()
+            .filter(|(tag, _)| self.rest.starts_with(tag))
+            .max_by_key(|(tag, _)| tag.len());
+        match found {
+            Some(&(tag, value)) => {
+                self.advance(tag.len());
+                Ok(value)
+            }
+            None => {
+                let names = options.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+                let expected = match names.split_last() {
+                    Some((last, [])) => last.to_string(),
+                    Some((last, init)) => format!("{} or {}", init.join(", "), last),
+                    None => "nothing".to_owned(),
+                };
+                Err(self.error(expected))
+            }
+        }
+    }
+
+    /// One or more `item`s separated by `sep`.
+    pub fn list<T>(&mut self, sep: &str, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut ret = vec![item(self)?];
+        while self.eat(sep) {
+            ret.push(item(self)?);
+        }
+        Ok(ret)
+    }
+
+    /// Whitespace separated unsigned numbers, up to whatever isn't one.
+    pub fn numbers<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        let mut ret = vec![];
+        loop {
+            self.spaces();
+            if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                return Ok(ret);
+            }
+            ret.push(self.unsigned()?);
+        }
+    }
+
+    /// Fails unless everything has been consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_line() {
+        let line = "Game 12: 3 blue, 4 red";
+        let mut s = Scanner::new(line);
+        s.tag("Game ").unwrap();
+        assert_eq!(s.unsigned::<u32>(), Ok(12));
+        s.tag(": ").unwrap();
+        let picks = s
+            .list(", ", |s| {
+                let n = s.unsigned::<u32>()?;
+                s.tag(" ")?;
+                Ok((n, s.word()?))
+            })
+            .unwrap();
+        assert_eq!(picks, vec![(3, "blue"), (4, "red")]);
+        assert!(s.end().is_ok());
+    }
+
+    #[test]
+    fn errors_point_into_input() {
+        let line = "R,6 (#70c710)";
+        let mut s = Scanner::new(line);
+        s.one_of(&[("R", 0), ("D", 1), ("L", 2), ("U", 3)]).unwrap();
+        let e = s.tag(" ").unwrap_err().locate(line);
+        assert_eq!((e.column, e.found.as_str()), (2, ",6"));
+        let e = Scanner::new("x").one_of(&[("a", 0), ("b", 1), ("c", 2)]).unwrap_err();
+        assert_eq!(e.expected, "a, b or c");
+        assert_eq!(Scanner::new("12 ").end().unwrap_err().found, "12");
+    }
+
+    #[test]
+    fn numbers() {
+        let mut s = Scanner::new(" 41 48  83 | 9");
+        assert_eq!(s.numbers::<u32>(), Ok(vec![41, 48, 83]));
+        assert_eq!(s.rest(), "| 9");
+        assert_eq!(Scanner::new("-17x").signed::<i32>(), Ok(-17));
+        assert!(Scanner::new("-x").signed::<i32>().is_err());
+        assert_eq!(Scanner::new("300").unsigned::<u8>().unwrap_err().expected, "smaller number");
+    }
+
+    #[test]
+    fn one_of_prefers_longest() {
+        assert_eq!(Scanner::new("ab").one_of(&[("a", 1), ("ab", 2)]), Ok(2));
+    }
+
+    #[test]
+    fn garbage_never_panics() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green";
+        for len in 0..=sample.len() {
+            for ch in ['x', ' ', ',', '-', '9', 'é'] {
+                let mut line = sample[..len].to_owned();
+                line.push(ch);
+                line.push_str(&sample[len..]);
+                let mut s = Scanner::new(&line);
+                let _ = s.tag("Game ").and_then(|_| s.unsigned::<u8>()).and_then(|_| s.tag(": "));
+                let _ = s.list("; ", |s| {
+                    s.list(", ", |s| {
+                        s.signed::<i8>()?;
+                        s.spaces();
+                        s.word()
+                    })
+                });
+                let _ = s.end();
+            }
+        }
+    }
+}
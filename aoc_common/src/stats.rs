@@ -0,0 +1,36 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use crate::Answer;
+
+thread_local! {
+    static STATS: RefCell<BTreeMap<&'static str, Answer>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Remembers a solver-specific number (cycle length, path length, ...) for
+/// the runner to show next to the answer. Recording the same name twice
+/// keeps the last value.
+pub fn record(name: &'static str, value: impl Into<Answer>) {
+    STATS.with(|s| s.borrow_mut().insert(name, value.into()));
+}
+
+/// Everything recorded on this thread since the last call.
+pub fn take() -> BTreeMap<&'static str, Answer> {
+    STATS.with(|s| std::mem::take(&mut *s.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_and_take() {
+        record("cycle_length", 7usize);
+        record("cycle_length", 9usize);
+        record("start", -1i64);
+        let stats = take();
+        assert_eq!(stats["cycle_length"], Answer::Unsigned(9));
+        assert_eq!(stats["start"], Answer::Signed(-1));
+        assert!(take().is_empty());
+    }
+}
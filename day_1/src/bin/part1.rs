@@ -1,15 +1,10 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::process::ExitCode;
 
-fn value_from_line(line: &str) -> u32 {
-    let dig1 = line.chars().find(|c| c.is_ascii_digit()).unwrap().to_digit(10).unwrap();
-    let dig2 = line.chars().rfind(|c| c.is_ascii_digit()).unwrap().to_digit(10).unwrap();
-    return (10 * dig1) + dig2;
-}
+use aoc_common::Solution;
+use day_1::Day1;
 
-fn main() {
-    let file = File::open("input.txt").unwrap();
-    let reader = BufReader::new(file);
-    let sum = reader.lines().fold(0, |acc,line| acc+value_from_line(&line.unwrap()));
-    println!("Sum: {}", sum);
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let input = std::fs::read_to_string("input.txt").unwrap();
+    aoc_common::report("Sum", "input.txt", Day1::solve_part1(&input))
 }
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::{Parser, ValueEnum};
+use day_1::digits::Digits;
+use day_1::stream::{self, Mode};
+
+/// Sums calibration values of documents too big to read in one go
+#[derive(Parser)]
+struct Args {
+    file: PathBuf,
+    #[arg(short, long, default_value_t = 2)]
+    part: u8,
+    /// Extra digit words, see digits.example.toml. Implies part 2
+    #[arg(short, long)]
+    digits: Option<String>,
+    #[arg(short, long, value_enum, default_value_t = ModeArg::Parallel)]
+    mode: ModeArg,
+    /// Chunk size for the parallel path, in KiB
+    #[arg(long, default_value_t = stream::CHUNK_SIZE / 1024)]
+    chunk_kib: usize,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ModeArg {
+    Sequential,
+    Parallel,
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let args = Args::parse();
+    let digits = match (&args.digits, args.part) {
+        (Some(path), _) => match Digits::load(path) {
+            Ok(digits) => digits,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        (None, 1) => Digits::numerals(),
+        (None, _) => Digits::english(),
+    };
+    let mode = match args.mode {
+        ModeArg::Sequential => Mode::Sequential,
+        ModeArg::Parallel => Mode::Parallel,
+    };
+    let start = Instant::now();
+    match stream::sum_file(&args.file, &digits, mode, args.chunk_kib * 1024) {
+        Ok(sum) => {
+            println!("Sum: {}", sum);
+            eprintln!("took {:.1?}", start.elapsed());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}: {}", args.file.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Which spellings count as a digit, matched in one pass over each line.
+
+use aho_corasick::AhoCorasick;
+use aoc_common::ParseError;
+use toml::{Table, Value};
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The digits 0-9 plus any number of words for them. Matches may overlap,
+/// so in "oneight" the first digit is 1 and the last is 8.
+#[derive(Debug, Clone)]
+pub struct Digits {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Digits {
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let mut patterns = (0..10).map(|d| d.to_string()).collect::<Vec<_>>();
+        let mut values = (0..10).collect::<Vec<_>>();
+        for (word, value) in words {
+            patterns.push(word.as_ref().to_owned());
+            values.push(value);
+        }
+        // Standard match semantics, the only kind that reports overlapping matches.
+        let automaton = AhoCorasick::new(&patterns).unwrap();
+        Digits { automaton, values }
+    }
+
+    /// Just 0-9, as in part 1.
+    pub fn numerals() -> Self {
+        Digits::new::<&str>([])
+    }
+
+    /// 0-9 and "one" to "nine", as in part 2.
+    pub fn english() -> Self {
+        Digits::new(ENGLISH)
+    }
+
+    /// Words from a TOML file, either at the top level or grouped in tables
+    /// (e.g. one per language). The digits 0-9 are always included.
+    ///
+    /// ```toml
+    /// zero = 0
+    ///
+    /// [german]
+    /// eins = 1
+    /// zwei = 2
+    /// ```
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        fn collect(table: &Table, prefix: &str, words: &mut Vec<(String, u32)>) -> Result<(), String> {
+            for (key, value) in table {
+                if key.is_empty() {
+                    return Err(format!("{}empty words can't be digits", prefix));
+                }
+                match value {
+                    Value::Integer(n @ 0..=9) => words.push((key.clone(), *n as u32)),
+                    Value::Table(inner) if prefix.is_empty() => collect(inner, &format!("{}.", key), words)?,
+                    _ => return Err(format!("{}{} should be a digit from 0 to 9", prefix, key)),
+                }
+            }
+            Ok(())
+        }
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut words = vec![];
+        collect(&table, "", &mut words)?;
+        Ok(Digits::new(words))
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Digits::from_toml(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// First and last digit of the line as a two digit number, `None` if
+    /// there aren't any.
+    pub fn calibration_value(&self, line: impl AsRef<[u8]>) -> Option<u32> {
+        let mut first: Option<aho_corasick::Match> = None;
+        let mut last: Option<aho_corasick::Match> = None;
+        // Overlapping matches come out ordered by where they end, so the
+        // earliest start has to be looked for. Ties go to the longer word.
+        for m in self.automaton.find_overlapping_iter(line.as_ref()) {
+            if first.is_none_or(|f| (m.start(), f.len()) < (f.start(), m.len())) {
+                first = Some(m);
+            }
+            if last.is_none_or(|l| (m.start(), m.len()) > (l.start(), l.len())) {
+                last = Some(m);
+            }
+        }
+        Some(10 * self.values[first?.pattern()] + self.values[last?.pattern()])
+    }
+
+    /// Sum of all calibration values. A line without any digit is an error
+    /// pointing at that line.
+    pub fn sum(&self, input: &str) -> Result<u32, ParseError> {
+        input.lines().try_fold(0, |acc, line| match self.calibration_value(line) {
+            Some(value) => Ok(acc + value),
+            None => Err(ParseError::new("a digit", line).locate(input)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_words() {
+        let digits = Digits::english();
+        assert_eq!(digits.calibration_value("oneight"), Some(18));
+        assert_eq!(digits.calibration_value("9qb95oneightsf"), Some(98));
+        assert_eq!(digits.calibration_value("xtwone3four"), Some(24));
+        assert_eq!(digits.calibration_value("7pqrstsixteen"), Some(76));
+        assert_eq!(digits.calibration_value("eightwo"), Some(82));
+        assert_eq!(digits.calibration_value("five"), Some(55));
+        assert_eq!(digits.calibration_value("zero"), None);
+        assert_eq!(Digits::numerals().calibration_value("one2three"), Some(22));
+    }
+
+    #[test]
+    fn from_toml() {
+        let digits = Digits::from_toml("zero = 0\n[german]\neins = 1\nzwei = 2\n[french]\nhuit = 8\n").unwrap();
+        assert_eq!(digits.calibration_value("zweins"), Some(21));
+        assert_eq!(digits.calibration_value("zero5huit"), Some(8));
+        assert_eq!(digits.calibration_value("one"), None);
+        assert!(Digits::from_toml("ten = 10").unwrap_err().contains("ten should be a digit"));
+        assert!(Digits::from_toml("[a]\n[a.b]\nc = 1").unwrap_err().contains("a.b should be"));
+
+        let example = Digits::from_toml(include_str!("../digits.example.toml")).unwrap();
+        assert_eq!(example.calibration_value("zeroneunx"), Some(9));
+    }
+
+    #[test]
+    fn longer_alias_wins_on_same_start() {
+        let digits = Digits::new([("sev", 7), ("seventy", 0)]);
+        assert_eq!(digits.calibration_value("seventy"), Some(0));
+    }
+
+    #[test]
+    fn line_without_digits() {
+        let e = Digits::english().sum("1abc2\npqrstuvwx\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.to_string(), "line 2, column 1: expected a digit, found 'pqrstuvwx'");
+    }
+}
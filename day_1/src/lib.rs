@@ -0,0 +1,21 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod digits;
+pub mod part1;
+pub mod part2;
+pub mod stream;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn solve_part1(input: &str) -> Result<u32, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<u32, ParseError> {
+        part2::solve(input)
+    }
+}
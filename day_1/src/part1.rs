@@ -0,0 +1,7 @@
+use aoc_common::ParseError;
+
+use crate::digits::Digits;
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    Digits::numerals().sum(input)
+}
@@ -0,0 +1,29 @@
+use aoc_common::ParseError;
+
+use crate::digits::Digits;
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    Digits::english().sum(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_from_line() {
+        let digits = Digits::english();
+        //4nineeightseven2
+        assert_eq!(digits.calibration_value("4nineeightseven2"), Some(42));
+        //7pqrstsixteen
+        assert_eq!(digits.calibration_value("7pqrstsixteen"), Some(76));
+        //9qb95oneightsf
+        assert_eq!(digits.calibration_value("9qb95oneightsf"), Some(98));
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+        assert_eq!(solve(input), Ok(281));
+    }
+}
@@ -0,0 +1,213 @@
+//! Calibration documents too big to read into a `String`.
+//!
+//! Both paths give the same sum and report the same first bad line, so they
+//! can be checked against each other.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use aoc_common::ParseError;
+use rayon::prelude::*;
+
+use crate::digits::Digits;
+
+/// Default chunk size for the parallel path.
+pub const CHUNK_SIZE: usize = 4 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Buffered reads on one thread, memory use doesn't grow with the file.
+    Sequential,
+    /// Memory maps the file and sums chunks on all cores.
+    Parallel,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// `number` is 1-based, `line` comes without its line break.
+fn line_value(digits: &Digits, number: usize, line: &[u8]) -> Result<u64, ParseError> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    match digits.calibration_value(line) {
+        Some(value) => Ok(value as u64),
+        None => Err(ParseError::at(number, 1, "a digit", String::from_utf8_lossy(line))),
+    }
+}
+
+pub fn sum_reader(mut reader: impl BufRead, digits: &Digits) -> Result<u64, Error> {
+    let mut line = vec![];
+    let mut number = 0;
+    let mut sum = 0;
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(sum);
+        }
+        number += 1;
+        sum += line_value(digits, number, line.strip_suffix(b"\n").unwrap_or(&line))?;
+    }
+}
+
+/// Splits `bytes` into pieces of roughly `chunk_size`, each one ending right
+/// after a line break (or at the end of the input).
+pub fn chunks(bytes: &[u8], chunk_size: usize) -> Vec<&[u8]> {
+    let chunk_size = chunk_size.max(1);
+    let mut ret = vec![];
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let end = match rest.get(chunk_size - 1..).and_then(|tail| tail.iter().position(|&b| b == b'\n')) {
+            Some(pos) => chunk_size + pos,
+            None => rest.len(),
+        };
+        let (chunk, tail) = rest.split_at(end);
+        ret.push(chunk);
+        rest = tail;
+    }
+    ret
+}
+
+fn sum_chunk(chunk: &[u8], digits: &Digits) -> Result<u64, ParseError> {
+    let chunk = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+    chunk
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .try_fold(0, |acc, (idx, line)| Ok(acc + line_value(digits, idx + 1, line)?))
+}
+
+pub fn sum_parallel(bytes: &[u8], digits: &Digits, chunk_size: usize) -> Result<u64, ParseError> {
+    let chunks = chunks(bytes, chunk_size);
+    let sums = chunks.par_iter().map(|chunk| sum_chunk(chunk, digits)).collect::<Vec<_>>();
+    // Line numbers in errors are relative to the chunk, so count the lines
+    // before it. Only the first error matters, later chunks aren't counted.
+    let mut total = 0;
+    let mut lines_before = 0;
+    for (chunk, sum) in chunks.iter().zip(sums) {
+        match sum {
+            Ok(sum) => total += sum,
+            Err(mut e) => {
+                e.line += lines_before;
+                return Err(e);
+            }
+        }
+        lines_before += chunk.iter().filter(|&&b| b == b'\n').count();
+    }
+    Ok(total)
+}
+
+pub fn sum_file(path: &Path, digits: &Digits, mode: Mode, chunk_size: usize) -> Result<u64, Error> {
+    let file = File::open(path)?;
+    match mode {
+        Mode::Sequential => sum_reader(BufReader::with_capacity(1 << 16, file), digits),
+        Mode::Parallel => {
+            // SAFETY: the map is only ever read. If someone truncates the file
+            // while we're summing it all bets are off, same as any mmap.
+            let map = unsafe { memmap2::Mmap::map(&file)? };
+            Ok(sum_parallel(&map, digits, chunk_size)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic junk with digits and overlapping words in it.
+    fn document(lines: usize) -> String {
+        let pieces = ["oneight", "x", "7", "twone", "abc", "3", "nine", "qq", "eightwo", "5"];
+        let mut state = 12345u64;
+        let mut doc = String::new();
+        for _ in 0..lines {
+            let len = 1 + state % 6;
+            for _ in 0..len {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                doc.push_str(pieces[(state >> 33) as usize % pieces.len()]);
+            }
+            // Every line needs at least one digit.
+            doc.push('1');
+            doc.push('\n');
+        }
+        doc
+    }
+
+    #[test]
+    fn chunks_end_on_line_breaks() {
+        let text = b"ab\ncd\nef\ng";
+        assert_eq!(chunks(text, 1), vec![&b"ab\n"[..], b"cd\n", b"ef\n", b"g"]);
+        assert_eq!(chunks(text, 4), vec![&b"ab\ncd\n"[..], b"ef\ng"]);
+        assert_eq!(chunks(text, 100), vec![&text[..]]);
+        assert!(chunks(b"", 4).is_empty());
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let doc = document(5000);
+        let digits = Digits::english();
+        let expected = digits.sum(&doc).unwrap() as u64;
+        assert_eq!(sum_reader(doc.as_bytes(), &digits).unwrap(), expected);
+        for chunk_size in [1, 7, 100, 4096, doc.len() * 2] {
+            assert_eq!(sum_parallel(doc.as_bytes(), &digits, chunk_size), Ok(expected), "chunk size {}", chunk_size);
+        }
+        let crlf = doc.replace('\n', "\r\n");
+        assert_eq!(sum_parallel(crlf.as_bytes(), &digits, 64), Ok(expected));
+    }
+
+    #[test]
+    fn same_error_on_both_paths() {
+        let mut doc = document(300);
+        let bad = 211;
+        let start = doc.match_indices('\n').nth(bad - 2).unwrap().0 + 1;
+        let end = start + doc[start..].find('\n').unwrap();
+        doc.replace_range(start..end, "no digits here");
+        let digits = Digits::english();
+        let Err(Error::Parse(sequential)) = sum_reader(doc.as_bytes(), &digits) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(sequential.line, bad);
+        assert_eq!(sequential.found, "no digits here");
+        for chunk_size in [1, 50, 1000] {
+            assert_eq!(sum_parallel(doc.as_bytes(), &digits, chunk_size), Err(sequential.clone()));
+        }
+    }
+
+    #[test]
+    fn files() {
+        let doc = document(1000);
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), &doc).unwrap();
+        let digits = Digits::english();
+        let sequential = sum_file(file.path(), &digits, Mode::Sequential, CHUNK_SIZE).unwrap();
+        let parallel = sum_file(file.path(), &digits, Mode::Parallel, 256).unwrap();
+        assert_eq!(sequential, parallel);
+
+        std::fs::write(file.path(), "").unwrap();
+        assert_eq!(sum_file(file.path(), &digits, Mode::Parallel, 256).unwrap(), 0);
+    }
+}
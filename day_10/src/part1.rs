@@ -0,0 +1,283 @@
+use std::fmt::Display;
+use std::collections::LinkedList;
+
+use aoc_common::ParseError;
+use grid::{Grid, Point, Vector};
+use tracing::{debug, trace};
+
+/*
+enum Offset {
+    Zero,
+    Pos(usize),
+    Neg(usize)
+}
+const NEIGHBORS: [(Offset, Offset); 8] = [
+    (Offset::Neg(1), Offset::Neg(1)),
+    (Offset::Zero,  Offset::Neg(1)),
+    (Offset::Pos(1),  Offset::Neg(1)),
+    (Offset::Neg(1),  Offset::Zero),
+    (Offset::Pos(1),   Offset::Zero),
+    (Offset::Neg(1),  Offset::Pos(1)),
+    (Offset::Zero,   Offset::Pos(1)),
+    (Offset::Pos(1),   Offset::Pos(1))
+];
+*/
+const NEIGHBORS: [Vector; 4] = [
+    Vector::UP,
+    Vector::LEFT,
+    Vector::RIGHT,
+    Vector::DOWN,
+];
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+enum MapTile {
+    LeftToRight,
+    TopToBottom,
+    TopToRight,
+    TopToLeft,
+    BottomToRight,
+    BottomToLeft,
+    Ground,
+    Start
+}
+
+impl MapTile {
+    fn from_char(c: char) -> Option<MapTile> {
+        use MapTile::*;
+        match c {
+            'S' => Some(Start),
+            '.' => Some(Ground),
+            '|' => Some(TopToBottom),
+            '-' => Some(LeftToRight),
+            'F' => Some(BottomToRight),
+            'J' => Some(TopToLeft),
+            '7' => Some(BottomToLeft),
+            'L' => Some(TopToRight),
+            _ => None
+        }
+    }
+
+    fn to_char(self) -> char {
+        use MapTile::*;
+        match self {
+            LeftToRight => '-',
+            TopToBottom => '|',
+            TopToRight => 'L',
+            TopToLeft => 'J',
+            BottomToRight => 'F',
+            BottomToLeft => '7',
+            Ground => '.',
+            Start => 'S'
+        }
+    }
+
+    fn can_connect(&self, rhs: MapTile, offs: Vector) -> bool {
+        use MapTile::*;
+
+        match self {
+            LeftToRight => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | TopToRight | BottomToRight),
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | TopToLeft | BottomToLeft),
+                _ => false
+            },
+            TopToBottom => match offs {
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToRight | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToRight | TopToLeft),
+                _ => false
+            },
+            TopToRight => match offs {
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | BottomToLeft | TopToLeft),
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToLeft | BottomToRight),
+                _ => false
+            },
+            TopToLeft => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | BottomToRight | TopToRight),
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToLeft | BottomToRight),
+                _ => false
+            },
+            BottomToRight => match offs {
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | TopToLeft | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToRight | TopToLeft),
+                _ => false
+            },
+            BottomToLeft => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | TopToRight | BottomToRight),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToLeft | TopToRight),
+                _ => false
+            },
+            Ground => false,
+            Start => match offs {
+                Vector(-1, 0) => matches!(rhs, LeftToRight | TopToRight | BottomToRight),
+                Vector(1, 0) => matches!(rhs, LeftToRight | TopToLeft | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, TopToBottom | TopToRight | TopToLeft),
+                Vector(0, -1) => matches!(rhs, TopToBottom | BottomToRight | BottomToLeft),
+                _ => true
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    coord: Point,
+    tile: MapTile,
+    dist_from_start: usize,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Map {
+    data: Grid<MapTile>,
+    start: Point,
+    nodes: LinkedList<Node>
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::try_parse(input, MapTile::from_char)?;
+        let start = data
+            .position(|tile| matches!(tile, MapTile::Start))
+            .ok_or_else(|| ParseError::end_of_input("start tile 'S'", input))?;
+        Ok(Map {
+            data,
+            start,
+            nodes: LinkedList::new()
+        })
+    }
+
+    fn find_end(&mut self) -> Result<Node, ParseError> {
+        let coord_start = self.start;
+        debug!("Start at: {:?}", coord_start);
+
+        let start = self.get_tile(coord_start);
+        let adj_start = self.get_connected_neighbors(coord_start)?;
+        // ((Coords), dist_to_start)
+        let mut path_1_last = Node {
+            dist_from_start: 0,
+            coord: coord_start,
+            tile: *start
+        };
+        let mut path_2_last = Node {
+            dist_from_start: 0,
+            coord: coord_start,
+            tile: *start
+        };
+        let mut path_1_cur = adj_start[0];
+        let mut path_2_cur = adj_start[1];
+        while path_1_cur.0 != path_2_cur.0 {
+            let tmp1 = self.get_connected_neighbors(path_1_cur.0)?;
+            let tmp2 = self.get_connected_neighbors(path_2_cur.0)?;
+
+            let neigh_1 = tmp1.iter()
+                .find(|(coord,_)| *coord != path_1_last.coord)
+                .unwrap();
+            let neigh_2 = tmp2.iter()
+                .find(|(coord,_)| *coord != path_2_last.coord)
+                .unwrap();
+
+            path_1_last = Node {
+                dist_from_start: path_1_last.dist_from_start + 1,
+                coord: path_1_cur.0,
+                tile: *path_1_cur.1
+            };
+            path_2_last = Node {
+                dist_from_start: path_2_last.dist_from_start + 1,
+                coord: path_2_cur.0,
+                tile: *path_2_cur.1
+            };
+
+            path_1_cur = *neigh_1;
+            path_2_cur = *neigh_2;
+        }
+        path_1_last = Node {
+            dist_from_start: path_1_last.dist_from_start + 1,
+            coord: path_1_cur.0,
+            tile: *path_1_cur.1
+        };
+        path_2_last = Node {
+            dist_from_start: path_2_last.dist_from_start + 1,
+            coord: path_2_cur.0,
+            tile: *path_2_cur.1
+        };
+
+        debug!("Arrived at: {:?}", path_1_last);
+        debug!("Arrived at: {:?}", path_2_last);
+
+        Ok(path_1_last)
+    }
+
+    fn get_tile(&self, coord: Point) -> &MapTile {
+        &self.data[coord]
+    }
+
+    /// The two tiles `coord` is joined to, anything else means the loop is
+    /// broken there.
+    fn get_connected_neighbors(&self, coord: Point) -> Result<Vec<(Point, &MapTile)>, ParseError> {
+        let this_tile = self.get_tile(coord);
+        let mut ret = vec![];
+        for neighbor in &NEIGHBORS {
+            let Some(neighbor_coord) = self.data.step(coord, *neighbor) else {
+                continue;
+            };
+            let neighbor_tile = self.get_tile(neighbor_coord);
+            if this_tile.can_connect(*neighbor_tile, *neighbor) {
+                ret.push((neighbor_coord, neighbor_tile));
+            }
+        }
+        trace!("Neighbors of {:?} are {:?}", coord, ret);
+        if ret.len() != 2 {
+            return Err(ParseError::at(coord.1 + 1, coord.0 + 1, "a pipe joined at both ends", this_tile.to_char()));
+        }
+        Ok(ret)
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.data.rows() {
+            for col in row {
+                write!(f, "{}", col.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut map = Map::new(input)?;
+
+    trace!("\n{}", map);
+
+    let end = map.find_end()?;
+    Ok(end.dist_from_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construct_map() {
+        let input = include_str!("../input.txt");
+
+        let map = Map::new(input).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), map.to_string().lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_invalid_tile() {
+        let err = Map::new("S-7\n|x|\nL-J\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        assert_eq!(solve("...\n.7.\n").unwrap_err().to_string(), "line 3, column 1: expected start tile 'S', found end of input");
+        assert_eq!(solve("S-7\n|.|\nL-J\n"), Ok(4));
+        let err = solve("S-7\n|.|\nL-.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "|"));
+    }
+}
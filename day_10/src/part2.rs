@@ -0,0 +1,362 @@
+use std::fmt::Display;
+use std::cell::RefCell;
+
+use aoc_common::ParseError;
+use grid::{Grid, Point, Vector};
+use tracing::{debug, trace, Level};
+
+/*
+enum Offset {
+    Zero,
+    Pos(usize),
+    Neg(usize)
+}
+const NEIGHBORS: [(Offset, Offset); 8] = [
+    (Offset::Neg(1), Offset::Neg(1)),
+    (Offset::Zero,  Offset::Neg(1)),
+    (Offset::Pos(1),  Offset::Neg(1)),
+    (Offset::Neg(1),  Offset::Zero),
+    (Offset::Pos(1),   Offset::Zero),
+    (Offset::Neg(1),  Offset::Pos(1)),
+    (Offset::Zero,   Offset::Pos(1)),
+    (Offset::Pos(1),   Offset::Pos(1))
+];
+*/
+const NEIGHBORS: [Vector; 4] = [
+    Vector::UP,
+    Vector::LEFT,
+    Vector::RIGHT,
+    Vector::DOWN,
+];
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MapTile {
+    LeftToRight,
+    TopToBottom,
+    TopToRight,
+    TopToLeft,
+    BottomToRight,
+    BottomToLeft,
+    Ground,
+    Start,
+}
+
+impl MapTile {
+    fn from_char(c: char) -> Option<MapTile> {
+        use MapTile::*;
+        match c {
+            'S' => Some(Start),
+            '.' => Some(Ground),
+            '|' => Some(TopToBottom),
+            '-' => Some(LeftToRight),
+            'F' => Some(BottomToRight),
+            'J' => Some(TopToLeft),
+            '7' => Some(BottomToLeft),
+            'L' => Some(TopToRight),
+            _ => None
+        }
+    }
+
+    fn to_char(self) -> char {
+        use MapTile::*;
+        match self {
+            LeftToRight => '-',
+            TopToBottom => '|',
+            TopToRight => 'L',
+            TopToLeft => 'J',
+            BottomToRight => 'F',
+            BottomToLeft => '7',
+            Ground => '.',
+            Start => 'S'
+        }
+    }
+
+    fn can_connect(&self, rhs: MapTile, offs: Vector) -> bool {
+        use MapTile::*;
+
+        match self {
+            LeftToRight => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | TopToRight | BottomToRight),
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | TopToLeft | BottomToLeft),
+                _ => false
+            },
+            TopToBottom => match offs {
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToRight | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToRight | TopToLeft),
+                _ => false
+            },
+            TopToRight => match offs {
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | BottomToLeft | TopToLeft),
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToLeft | BottomToRight),
+                _ => false
+            },
+            TopToLeft => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | BottomToRight | TopToRight),
+                Vector(0, -1) => matches!(rhs, Start | TopToBottom | BottomToLeft | BottomToRight),
+                _ => false
+            },
+            BottomToRight => match offs {
+                Vector(1, 0) => matches!(rhs, Start | LeftToRight | TopToLeft | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToRight | TopToLeft),
+                _ => false
+            },
+            BottomToLeft => match offs {
+                Vector(-1, 0) => matches!(rhs, Start | LeftToRight | TopToRight | BottomToRight),
+                Vector(0, 1) => matches!(rhs, Start | TopToBottom | TopToLeft | TopToRight),
+                _ => false
+            },
+            Ground => false,
+            Start => match offs {
+                Vector(-1, 0) => matches!(rhs, LeftToRight | TopToRight | BottomToRight),
+                Vector(1, 0) => matches!(rhs, LeftToRight | TopToLeft | BottomToLeft),
+                Vector(0, 1) => matches!(rhs, TopToBottom | TopToRight | TopToLeft),
+                Vector(0, -1) => matches!(rhs, TopToBottom | BottomToRight | BottomToLeft),
+                _ => true
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    coord: Point,
+    tile: MapTile,
+    dist_from_start: usize,
+    offset_from_prev: Vector,
+    on_loop: bool
+}
+
+#[derive(Debug)]
+struct Map {
+    data: Grid<RefCell<Node>>,
+    start: Point,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut data = Grid::try_parse(input, MapTile::from_char)?.map(|&tile| {
+            RefCell::new(Node {
+                coord: Point(0, 0),
+                tile,
+                dist_from_start: 0,
+                offset_from_prev: Vector(0, 0),
+                on_loop: false
+            })
+        });
+        for (coord, node) in data.iter_mut() {
+            node.get_mut().coord = coord;
+        }
+        let start = data
+            .position(|node| node.borrow().tile == MapTile::Start)
+            .ok_or_else(|| ParseError::end_of_input("start tile 'S'", input))?;
+        Ok(Map { data, start })
+    }
+
+    fn mark_main_loop(&self) -> Result<&RefCell<Node>, ParseError> {
+        let coord_start = self.start;
+        debug!("Start at: {:?}", coord_start);
+
+        let start: &RefCell<Node> = self.get_tile(coord_start);
+        let adj_start = self.get_connected_neighbors(coord_start)?;
+        // ((Coords), dist_to_start)
+        let mut path_1_last = start;
+
+        let start = self.get_tile(coord_start);
+
+        let mut path_1_cur = adj_start[0].1;
+        while path_1_cur.borrow().coord != start.borrow().coord {
+            let tmp1 = self.get_connected_neighbors(path_1_cur.borrow().coord)?;
+
+            let neigh_1 = tmp1.iter()
+                .find(|node| node.1.borrow().coord != path_1_last.borrow().coord)
+                .unwrap_or_else(|| panic!("No neighbor for {:?} found", path_1_last.borrow().coord));
+
+            let cur_1_coord = path_1_cur.borrow().coord;
+            path_1_cur.borrow_mut().offset_from_prev = cur_1_coord - path_1_last.borrow().coord;
+            trace!("Path 1: {:?} -> {:?}", path_1_last, path_1_cur);
+
+            path_1_last = path_1_cur;
+            path_1_last.borrow_mut().dist_from_start += 1;
+            path_1_last.borrow_mut().on_loop = true;
+
+            path_1_cur = neigh_1.1;
+            assert!(neigh_1.0 != Vector(0, 0));
+        }
+            let cur_1_coord = path_1_cur.borrow().coord;
+            path_1_cur.borrow_mut().offset_from_prev = cur_1_coord - path_1_last.borrow().coord;
+            trace!("Path 1: {:?} -> {:?}", path_1_last, path_1_cur);
+        path_1_last = path_1_cur;
+        path_1_last.borrow_mut().dist_from_start += 1;
+
+        path_1_last.borrow_mut().on_loop = true;
+        start.borrow_mut().on_loop = true;
+
+        debug!("Arrived at: {:?}", path_1_last);
+
+        Ok(path_1_last)
+    }
+
+    fn calc_tiles_inside_loop(&self) -> usize {
+       self.data
+           .rows()
+           .enumerate()
+           .fold(0, |acc,(row_num,l)| {
+               let cnt = l
+               .iter()
+               .enumerate()
+               .fold(0, |acc,(idx,node)| {
+                   if node.borrow().on_loop {
+                       return acc;
+                   }
+
+                   if l.iter().enumerate().take_while(|(i,node)| *i < idx && !node.borrow().on_loop).count() == idx {
+                       return acc;
+                   }
+                   trace!("We at ({},{})", idx, row_num);
+                    if idx > l.len()-2 {
+                        return acc;
+                    }
+                    let mut prev_relevant: Option<Node> = None;
+                    let mut outside = true;
+                    for node in &l[idx+1..l.len()] {
+                        if !node.borrow().on_loop {
+                            continue;
+                        }
+                        match node.borrow().tile {
+                            MapTile::TopToRight | MapTile::BottomToRight => {
+                                prev_relevant = Some(*node.borrow());
+                                //outside = !outside;
+                            },
+                            MapTile::BottomToLeft if prev_relevant.is_some_and(|n| n.tile == MapTile::TopToRight) => {
+                                prev_relevant = None;
+                                outside = !outside;
+                            },
+                            MapTile::TopToLeft if prev_relevant.is_some_and(|n| n.tile == MapTile::BottomToRight) => {
+                                prev_relevant = None;
+                                outside = !outside;
+                            },
+                            MapTile::TopToBottom => {
+                                outside = !outside;
+                            },
+                            _ => {}
+                        }
+                        trace!("{:?}", node.borrow().tile);
+                    }
+                    acc + (!outside as usize)
+                   /*
+                   let cnt_intersec = ((l[idx+1..]
+                    .iter()
+                    .filter(|node| node.borrow().tile == MapTile::MainLoop)
+                    .fold(0, |acc,node| 
+                        let ret;
+                        acc + match node.borrow().offset_from_prev {
+                        (-1, _) | (_, -1) => 1,
+                        (1, _) | (_, 1) => -1,
+                        (0, 0) => 0,
+                        x => panic!("Oughtn't happen ({},{}): {:?}", node.borrow().coord.0, node.borrow().coord.1, x)
+                     }
+                    ) != 0) as usize);
+                    acc + cnt_intersec
+                   */
+               });
+               trace!("Row: {} tiles inside", cnt);
+               acc + cnt
+           })
+    }
+
+    fn get_tile(&self, coord: Point) -> &RefCell<Node> {
+        &self.data[coord]
+    }
+
+    /// The two tiles `coord` is joined to, anything else means the loop is
+    /// broken there.
+    fn get_connected_neighbors(&self, coord: Point) -> Result<Vec<(Vector, &RefCell<Node>)>, ParseError> {
+        let this_tile = self.get_tile(coord);
+        let mut ret = vec![];
+        for neighbor in &NEIGHBORS {
+            let Some(coord) = self.data.step(coord, *neighbor) else {
+                continue;
+            };
+            let neighbor_tile = self.get_tile(coord);
+            assert!(*neighbor != Vector(0, 0));
+            if this_tile.borrow().tile.can_connect(neighbor_tile.borrow().tile, *neighbor) {
+                ret.push((*neighbor, neighbor_tile));
+            }
+        }
+        if ret.len() != 2 {
+            let tile = this_tile.borrow().tile;
+            return Err(ParseError::at(coord.1 + 1, coord.0 + 1, "a pipe joined at both ends", tile.to_char()));
+        }
+        Ok(ret)
+    }
+
+    fn debug_out(&self) {
+        if !tracing::enabled!(Level::TRACE) {
+            return;
+        }
+        let mut out = String::new();
+        for row in self.data.rows() {
+            for col in row {
+                if col.borrow().on_loop {
+                    out.push(match col.borrow().offset_from_prev {
+                        Vector::UP => '^',
+                        Vector::DOWN => 'v',
+                        Vector::LEFT => '<',
+                        Vector::RIGHT => '>',
+                        _ => '?'
+                    });
+                } else {
+                    out.push('.');
+                }
+            }
+            out.push('\n');
+        }
+        trace!("\n{}", out);
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.data.rows() {
+            for col in row {
+                write!(f, "{}", col.borrow().tile.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let map = Map::new(input)?;
+
+    trace!("\n{}", map);
+
+    map.mark_main_loop()?;
+    trace!("\n{}", map);
+
+    let num_inside = map.calc_tiles_inside_loop();
+
+    map.debug_out();
+    Ok(num_inside)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construct_map() {
+        let input = include_str!("../input.txt");
+
+        let map = Map::new(input).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), map.to_string().lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_invalid_tile() {
+        let err = Map::new("S-7\n|x|\nL-J\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
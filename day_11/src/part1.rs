@@ -0,0 +1,142 @@
+use aoc_common::ParseError;
+use grid::Grid;
+use tracing::trace;
+
+fn expand_space(mut lines: Vec<String>) -> Vec<String> {
+    let mut expand_columns = vec![];
+    let mut expand_lines = vec![];
+    for (idx, _) in lines.first().unwrap().char_indices() {
+        let gal_in_column = lines
+            .iter()
+            .fold(0, |acc,l| acc + ((l.chars().nth(idx).unwrap() == '#') as usize));
+        if gal_in_column == 0 {
+            expand_columns.push(idx + expand_columns.len());
+        }
+    }
+    for (idx,line) in lines.iter().enumerate() {
+        if line.chars().all(|ch| ch == '.') {
+            expand_lines.push(idx + expand_lines.len());
+        }
+    }
+    for idx in &expand_lines {
+        lines.insert(*idx, lines[*idx].clone());
+    }
+    for line in &mut lines {
+        for &idx in &expand_columns {
+            line.insert(idx, '.');
+        }
+    }
+
+    lines
+}
+
+fn find_galaxies(v: &[String]) -> Vec<(usize,usize)> {
+    let mut ret = vec![];
+
+    for (idx,line) in v.iter().enumerate() {
+        let mut line_galaxies = line
+            .char_indices()
+            .filter(|(_, ch)| *ch == '#')
+            .map(move |(col_idx,_)| {
+                (col_idx, idx)
+            }).collect::<Vec<(usize,usize)>>();
+        ret.append(&mut line_galaxies);
+    }
+
+    ret
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let image = Grid::try_parse(input, |ch| matches!(ch, '.' | '#').then_some(ch))?;
+    let mut lines = image.rows().map(|row| row.iter().collect()).collect::<Vec<String>>();
+
+    lines = expand_space(lines);
+
+    for line in &lines {
+        trace!("{}", line);
+    }
+
+    let galaxies = find_galaxies(&lines);
+
+    trace!("{:?}", galaxies);
+
+    let mut total_dist = 0usize;
+    for (idx, coord) in galaxies.iter().enumerate() {
+        if idx == galaxies.len()-1 {
+            break;
+        }
+        total_dist += galaxies
+            .iter()
+            .skip(idx)
+            .fold(0, |acc,&this_coord| 
+                acc + (this_coord.0.abs_diff(coord.0)) +
+                      (this_coord.1.abs_diff(coord.1)));
+    }
+
+    Ok(total_dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_expand() {
+        let lines = r"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        let expected = r"....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......";
+        
+        let lines = lines.lines().map(|l| l.to_string()).collect::<Vec<String>>();
+        let expected = expected.lines().map(|l| l.to_string()).collect::<Vec<String>>();
+        assert_eq!(expand_space(lines), expected);
+    }
+
+    #[test]
+    fn test_find_galaxies() {
+        let expected = r"....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......";
+        let expected = expected.lines().map(|l| l.to_string()).collect::<Vec<String>>();
+
+        let galaxies = find_galaxies(&expected);
+
+        assert_eq!(galaxies, [(4, 0), (9, 1), (0, 2), (8, 5), (1, 6), (12, 7), (9, 10), (0, 11), (5, 11)]);
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        let err = solve("hello world").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 1, "h"));
+        assert_eq!(solve("#..\n.#\n").unwrap_err().line, 2);
+        assert_eq!(solve("").unwrap_err().to_string(), "line 1, column 1: expected at least one row, found end of input");
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::{ParseError, Scanner};
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Arrangement {
+    data: String,
+    layout: Vec<usize>
+}
+
+impl Arrangement {
+    // ???.### 1,1,3
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let data = scanner.take_while1("?, # or .", |c| matches!(c, '?' | '#' | '.'))?;
+        scanner.tag(" ")?;
+        let layout = scanner.list(",", Scanner::unsigned::<usize>)?;
+        scanner.end()?;
+        Ok(Self {
+            data: data.to_string(),
+            layout
+        })
+    }
+}
+
+fn find_possible_permutations(
+    data: &str, 
+) -> Vec<String>
+{
+    if data.len() == 1 {
+        match data.chars().next().unwrap() {
+            '?' => return vec![ "#".to_string(), ".".to_string() ],
+            '#' => return vec![ "#".to_string() ],
+            '.' => return vec![ '.'.to_string() ],
+            _ => panic!("Cannot happen")
+        }
+    }
+    let mut ret: Vec<String> = Vec::new();
+    match data.chars().next().unwrap() {
+        '?' => {
+            for s in find_possible_permutations(&data[1..]) {
+                let mut new_str = String::from('#');
+                new_str.push_str(&s);
+                ret.push(new_str);
+                let mut new_str = String::from('.');
+                new_str.push_str(&s);
+                ret.push(new_str);
+            }
+        },
+        '#' => {
+            for s in find_possible_permutations(&data[1..]) {
+                let mut new_str = String::from('#');
+                new_str.push_str(&s);
+                ret.push(new_str);
+            }
+        },
+        '.' => {
+            for s in find_possible_permutations(&data[1..]) {
+                let mut new_str = String::from('.');
+                new_str.push_str(&s);
+                ret.push(new_str);
+            }
+        },
+        _ => panic!("Cannot happen")
+    }
+
+    ret
+}
+
+#[allow(dead_code)]
+fn permutation_is_group(perm: &str, _len: usize, need_beg_sep: bool, need_end_sep: bool) -> bool {
+    if need_beg_sep && !perm.starts_with('.') {
+        return false;
+    }
+    if need_end_sep && !perm.ends_with('.') {
+        return false;
+    }
+    let mut idx_start = 0usize;
+    while idx_start < perm.len() &&  perm.chars().nth(idx_start).unwrap() == '.' {
+        idx_start += 1;
+    }
+    let mut idx_end = perm.len() - 1;
+    while idx_end > 0 && perm.chars().nth(idx_end).unwrap() == '.' {
+        idx_end -= 1;
+    }
+    perm[idx_start..idx_end].chars().all(|c| c == '#')
+}
+
+fn get_groups_from_perm(perm: &str) -> Vec<usize> {
+    perm.split('.').filter(|s| !s.is_empty()).map(|str| str.len()).collect()
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let arrangements = input
+        .lines()
+        .map(Arrangement::new)
+        .collect::<Result<Vec<Arrangement>, _>>()
+        .map_err(|e| e.locate(input))?;
+
+    let mut sum = 0usize;
+    for a in arrangements {
+        let perms = find_possible_permutations(&a.data);
+        /*
+        println!("{:?}", a.data);
+        for p in &perms {
+            println!("  {:?}", p);
+        }
+        println!("----------------");
+        */
+
+        sum += perms.iter().map(|p| get_groups_from_perm(p)).filter(|v_groups| *v_groups == a.layout).count()
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_arrangement() {
+        let content = r"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    
+        let v = content.lines().map(|l| Arrangement::new(l).unwrap()).collect::<Vec<Arrangement>>();
+     
+        assert_eq!(v.len(), 6);
+        assert_eq!(v[0].data, "???.###");
+        assert_eq!(v[0].layout, vec![1,1,3]);
+        assert_eq!(v[1].data, ".??..??...?##.");
+        assert_eq!(v[1].layout, vec![1,1,3]);
+        assert_eq!(v[2].data, "?#?#?#?#?#?#?#?");
+        assert_eq!(v[2].layout, vec![1,3,1,6]);
+        assert_eq!(v[3].data, "????.#...#...");
+        assert_eq!(v[3].layout, vec![4,1,1]);
+        assert_eq!(v[4].data, "????.######..#####.");
+        assert_eq!(v[4].layout, vec![1,6,5]);
+        assert_eq!(v[5].data, "?###????????");
+        assert_eq!(v[5].layout, vec![3,2,1]);
+    }
+
+    #[test]
+    fn test_find_possible_permutations() {
+        let perm = find_possible_permutations("???");
+        assert_eq!(perm.len(), 8);
+        assert_eq!(perm[0], "###");
+        assert_eq!(perm[1], ".##");
+        assert_eq!(perm[2], "#.#");
+        assert_eq!(perm[3], "..#");
+        assert_eq!(perm[4], "##.");
+        assert_eq!(perm[5], ".#.");
+        assert_eq!(perm[6], "#..");
+        assert_eq!(perm[7], "...");
+    }
+
+    #[test]
+    fn test_permutation_is_group() {
+        assert!(permutation_is_group("###", 3, false, false));
+        assert!(!permutation_is_group("###", 3, true, false));
+        assert!(!permutation_is_group("###", 3, false, true));
+        assert!(!permutation_is_group("###", 3, true, true));
+
+    }
+
+    #[test]
+    fn test_get_groups_from_perm() {
+        let perm = "##.....###..#####";
+        assert_eq!(get_groups_from_perm(perm), vec![2,3,5]);
+        let perm = "##.###.###..#####";
+        assert_eq!(get_groups_from_perm(perm), vec![2,3,3,5]);
+    }
+
+    #[test]
+    fn test_rejects_bad_arrangements() {
+        assert_eq!(Arrangement::new("???.###").unwrap_err().expected, "' '");
+        assert_eq!(Arrangement::new("???.### 1,x").unwrap_err().found, "x");
+        let err = solve("???.### 1,1,3\nhello world\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,110 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::pattern;
+
+fn find_reflection_idx(pattern: &[&str], horizontal: bool) -> Option<(usize,usize)> {
+    let mut ret: Option<(usize, usize)> = None;
+    if horizontal {
+        let cnt = pattern.len();
+        for (idx,&line) in pattern.iter().enumerate() {
+            if idx == cnt - 1 {
+                break; 
+            }
+            if *line == **pattern.get(idx+1).unwrap(){
+                trace!("horz: {} == {}", idx, idx+1);
+                let mut cmp_idx = 1usize;
+                let mut invalid = false;
+                while cmp_idx+idx+1 < cnt && cmp_idx <= idx {
+                    if *pattern.get(idx+1+cmp_idx).unwrap() != *pattern.get(idx-cmp_idx).unwrap() {
+                        trace!("horz: {} != {}", idx+1+cmp_idx, idx-cmp_idx);
+                        invalid = true;
+                        break;
+                    }
+                    if cmp_idx == idx {
+                        cmp_idx += 1;
+                        break;
+                    }
+                    cmp_idx += 1;
+                }
+                if invalid {
+                    continue;
+                }
+                ret = Some((idx, cmp_idx));
+                break;
+            }
+        }
+    } else {
+        let cnt = pattern.first().unwrap().len();
+        let num_lines = pattern.len();
+        for idx in 0..cnt {
+            if idx == cnt - 1 {
+                break; 
+            }
+            let ident_count = pattern.iter()
+                .enumerate()
+                .filter(|&(_, &l)| l.chars().nth(idx) == l.chars().nth(idx+1))
+                .count();
+            if ident_count == num_lines
+            {
+                let mut cmp_idx = 1usize;
+                let mut invalid = false;
+                while cmp_idx+idx+1 < cnt && cmp_idx <= idx {
+                    if pattern.iter()
+                                .enumerate()
+                                .filter(|&(_, &l)| l.chars().nth(idx-cmp_idx) == l.chars().nth(idx+1+cmp_idx))
+                                .count() != num_lines
+                    {
+                        trace!("vert: {} != {}", idx+1+cmp_idx, idx-cmp_idx);
+                        invalid = true;
+                        break;
+                    }
+                    if cmp_idx == idx {
+                        cmp_idx += 1;
+                        break;
+                    }
+                    cmp_idx += 1;
+                }
+                if invalid {
+                    continue;
+                }
+                ret = Some((idx, cmp_idx));
+                break;
+            }
+        }
+
+    }
+
+    ret
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let patterns = pattern::parse(input)?;
+
+    let mut sum = 0usize;
+    for pattern in patterns {
+        for line in &pattern {
+            trace!("{}", line);
+        }
+
+        let ref_horz = find_reflection_idx(&pattern, true);
+        let ref_vert = find_reflection_idx(&pattern, false);
+        if let (Some(_), Some(_)) = (ref_vert, ref_horz) {
+            return Err(ParseError::new("only one line of reflection", pattern[0]).locate(input));
+        } else if let Some(vert) = ref_vert {
+            trace!("Axis: vertical");
+            let val = vert.0 + 1; 
+            trace!("{:?}, left: {}", vert, val);
+            sum += val;
+        } else if let Some(horz) = ref_horz {
+            trace!("Axis: horizontal");
+            let val = (horz.0 + 1) * 100;
+            trace!("{:?}, above: {}", horz, val);
+            sum += val;
+        } else {
+            return Err(ParseError::new("a line of reflection", pattern[0]).locate(input));
+        }
+        trace!("---------------------");
+    }
+    Ok(sum)
+}
@@ -0,0 +1,47 @@
+use aoc_common::{ParseError, Scanner};
+
+/// Splits the input into its blank-line separated patterns of ash (`.`) and
+/// rocks (`#`). Every row of a pattern has to be as wide as its first one.
+pub fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    let trimmed = input.trim_end_matches('\n');
+    if trimmed.is_empty() {
+        return Err(ParseError::end_of_input("a pattern", input));
+    }
+    trimmed
+        .split("\n\n")
+        .map(|block| parse_pattern(block).map_err(|e| e.locate(input)))
+        .collect()
+}
+
+fn parse_pattern(block: &str) -> Result<Vec<&str>, ParseError> {
+    let mut rows: Vec<&str> = vec![];
+    for line in block.lines() {
+        let mut s = Scanner::new(line);
+        let row = s.take_while1("# or .", |c| c == '#' || c == '.')?;
+        if !s.is_empty() {
+            return Err(s.error("# or ."));
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(format!("{} columns", first.len()), row));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_patterns() {
+        assert_eq!(parse("#.#\n..#\n\n##\n.#\n").unwrap().len(), 2);
+        let err = parse("#.#\n..#\n\nhello world\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 1, "hello"));
+        let err = parse("#.#\n..\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "3 columns"));
+        assert_eq!(parse("\n").unwrap_err().to_string(), "line 2, column 1: expected a pattern, found end of input");
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn solve_part1(input: &str) -> Result<u32, ParseError> {
+        Ok(part1::solve(input))
+    }
+
+    fn solve_part2(input: &str) -> Result<u32, ParseError> {
+        part2::solve(input)
+    }
+}
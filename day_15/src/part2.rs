@@ -0,0 +1,101 @@
+use aoc_common::{ParseError, Scanner};
+use tracing::trace;
+
+#[derive(Debug,PartialEq)]
+enum Operation {
+    Add(u32),
+    Remove
+}
+
+fn hash(s: &str) -> u8 {
+    let mut cur_val = 0u32;
+    for ch in s.bytes() {
+        cur_val += ch as u32;
+        cur_val *= 17;
+        cur_val %= 256;
+    }
+    assert!(cur_val < 256);
+    cur_val as u8
+}
+
+#[derive(PartialEq,Debug)]
+struct Step(String, Operation);
+
+// qm=3 or sdula-
+fn extract_info(s: &str) -> Result<Step, ParseError> {
+    let mut s = Scanner::new(s);
+    let label = s.take_while1("label", |c| c.is_ascii_lowercase())?;
+    let op = if s.eat("-") {
+        Operation::Remove
+    } else {
+        s.tag("=")?;
+        Operation::Add(s.unsigned()?)
+    };
+    s.end()?;
+
+    Ok(Step(label.to_owned(), op))
+}
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let steps = input.split([',', '\n']).filter(|s| !s.is_empty());
+
+    let mut boxes: [Vec<(String, u32)>; 256] = std::array::from_fn(|_| Vec::new());
+
+    for step_str in steps {
+        let step = extract_info(step_str).map_err(|e| e.locate(input))?;
+        let box_number = hash(&step.0) as usize;
+        trace!("{:?} -> {}", step, box_number);
+        let container = boxes.get_mut(box_number).unwrap();
+        match step.1 {
+            Operation::Remove => {
+                if let Some(idx) = container.iter().position(|s| s.0 == step.0) {
+                    container.remove(idx);
+                }
+                trace!("Removed {} from {}", step.0, box_number);
+            },
+            Operation::Add(focal_length) => {
+                let elem = container.iter_mut().find(|s| s.0 == step.0);
+                if let Some(lens) = elem {
+                    lens.1 = focal_length;
+                    trace!("Set focal length of {} to {} in {}", step.0, focal_length, box_number);
+                } else {
+                    trace!("Adding lense {},{} in {}", step.0, focal_length, box_number);
+                    container.push((step.0, focal_length)); 
+                }
+            }
+        }
+    }
+
+    Ok(boxes
+        .iter()
+        .enumerate()
+        .map(|(idx,container)|
+            container
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc,(cont_idx,lense)| 
+                acc + ((idx+1) as u32 * (cont_idx+1) as u32 * lense.1)
+            )
+        )
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("ot=9"), 9);
+    }
+
+    #[test]
+    fn test_extract() {
+        assert!(extract_info("qm=3") == Ok(Step("qm".to_owned(), Operation::Add(3))));
+        assert!(extract_info("sdula-") == Ok(Step("sdula".to_owned(), Operation::Remove)));
+        assert_eq!(extract_info("qm=").unwrap_err().expected, "number");
+        assert_eq!(extract_info("qm=3x").unwrap_err().found, "x");
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &str) -> Result<usize, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<usize, ParseError> {
+        part2::solve(input)
+    }
+}
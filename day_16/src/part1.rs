@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use aoc_common::ParseError;
+use grid::{Point, Vector};
+use tracing::{trace, Level};
+
+type Grid = grid::Grid<GridCell>;
+type Vec2 = Vector;
+type GridPos = Point;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[allow(dead_code)]
+struct GridCell {
+    cell: Tile,
+    energized: bool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    /// `.`
+    Empty,
+    /// `\`
+    MirrorDown,
+    /// `/`
+    MirrorUp,
+    /// `-`
+    SplitHorizontal,
+    /// `|`
+    SplitVertical
+}
+
+impl GridCell {
+    fn new(ch: char) -> Option<Self> {
+        let cell = match ch {
+            '.' => Tile::Empty,
+            '\\' => Tile::MirrorDown,
+            '/' => Tile::MirrorUp,
+            '-' => Tile::SplitHorizontal,
+            '|' => Tile::SplitVertical,
+            _ => return None
+        };
+        Some(GridCell {
+            cell,
+            energized: false
+        })
+    }
+}
+
+
+fn trace_beam(grid: &mut Grid, start: GridPos, move_dir: Vec2, beam: usize) -> Option<(GridPos, [Vec2; 2])> {
+    let mut cur_pos = start;
+    let mut cur_dir = move_dir;
+    loop {
+        let Some(new_pos) = grid.step(cur_pos, cur_dir) else {
+            trace!("BEAM {}, END: Leaving grid at {:?}+{:?}", beam, cur_pos, cur_dir);
+            break;
+        };
+        cur_pos = new_pos;
+        let cur_cell = &mut grid[cur_pos];
+        cur_cell.energized = true; 
+        match cur_cell.cell {
+            Tile::Empty => trace!("BEAM {}, PASS THROUGH (.) => {:?}", beam, cur_pos),
+            Tile::MirrorDown => {
+                cur_dir = Vector(cur_dir.1, cur_dir.0);
+                trace!("BEAM {}, REFLECT (\\) => {:?} -> {:?}", beam, cur_pos, cur_dir);
+            },
+            Tile::MirrorUp => {
+                cur_dir = Vector(-cur_dir.1, -cur_dir.0);
+                trace!("BEAM {}, REFLECT (/) => {:?} -> {:?}", beam, cur_pos, cur_dir);
+            },
+            Tile::SplitHorizontal => {
+                match cur_dir {
+                    Vector::RIGHT | Vector::LEFT => (),
+                    Vector::DOWN | Vector::UP => return Some((cur_pos, [Vector::RIGHT, Vector::LEFT])),
+                    _ => panic!("Shouldn't happen")
+                }
+            },
+            Tile::SplitVertical => {
+                match cur_dir {
+                    Vector::RIGHT | Vector::LEFT => return Some((cur_pos, [Vector::DOWN, Vector::UP])),
+                    Vector::DOWN | Vector::UP => (),
+                    _ => panic!("Shouldn't happen")
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let mut grid = Grid::try_parse(input, GridCell::new)?;
+
+    grid[Point(0, 0)].energized = true;
+    let mut start_queue = VecDeque::from([(Point(0, 0), Vector::RIGHT)]);
+    let mut visited = vec![];
+    let mut beam = 0usize;
+    while !start_queue.is_empty() {
+        let start = start_queue.pop_front().unwrap();
+        if visited.contains(&start) {
+            continue;
+        }
+        let continuation = trace_beam(&mut grid, start.0, start.1, beam);
+        visited.push(start);
+        if let Some(c) = continuation {
+            trace!("SPLIT: ({:?}) <- {:?} -> ({:?})", c.1[0], c.0, c.1[1]);
+            start_queue.push_back((c.0, c.1[0])); 
+            start_queue.push_back((c.0, c.1[1])); 
+        }
+        beam += 1;
+    }
+
+    if tracing::enabled!(Level::TRACE) {
+        trace!("\n{}", grid.map(|cell| if cell.energized { '#' } else { '.' }));
+    }
+
+    Ok(grid
+        .iter()
+        .filter(|(_, c)| c.energized)
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_tiles() {
+        assert_eq!(solve(".|.\n\\x.\n").unwrap_err().to_string(), "line 2, column 2: expected grid cell, found 'x'");
+        assert_eq!(solve("").unwrap_err().expected, "at least one row");
+        assert_eq!(solve(".|\n...\n").unwrap_err().line, 2);
+    }
+}
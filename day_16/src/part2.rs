@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use aoc_common::ParseError;
+use grid::{Point, Vector};
+use tracing::trace;
+
+type Grid = grid::Grid<GridCell>;
+type Vec2 = Vector;
+type GridPos = Point;
+
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct GridCell {
+    cell: Tile,
+    energized: bool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    /// `.`
+    Empty,
+    /// `\`
+    MirrorDown,
+    /// `/`
+    MirrorUp,
+    /// `-`
+    SplitHorizontal,
+    /// `|`
+    SplitVertical
+}
+
+impl GridCell {
+    fn new(ch: char) -> Option<Self> {
+        let cell = match ch {
+            '.' => Tile::Empty,
+            '\\' => Tile::MirrorDown,
+            '/' => Tile::MirrorUp,
+            '-' => Tile::SplitHorizontal,
+            '|' => Tile::SplitVertical,
+            _ => return None
+        };
+        Some(GridCell {
+            cell,
+            energized: false
+        })
+    }
+}
+
+
+fn trace_beam(grid: &mut Grid, start: GridPos, move_dir: Vec2, beam: usize) -> Option<(GridPos, [Vec2; 2])> {
+    let mut cur_pos = start;
+    let mut cur_dir = move_dir;
+    loop {
+        let Some(new_pos) = grid.step(cur_pos, cur_dir) else {
+            trace!("BEAM {}, END: Leaving grid at {:?}+{:?}", beam, cur_pos, cur_dir);
+            break;
+        };
+        cur_pos = new_pos;
+        let cur_cell = &mut grid[cur_pos];
+        cur_cell.energized = true; 
+        match cur_cell.cell {
+            Tile::Empty => trace!("BEAM {}, PASS THROUGH (.) => {:?}", beam, cur_pos),
+            Tile::MirrorDown => {
+                cur_dir = Vector(cur_dir.1, cur_dir.0);
+                trace!("BEAM {}, REFLECT (\\) => {:?} -> {:?}", beam, cur_pos, cur_dir);
+            },
+            Tile::MirrorUp => {
+                cur_dir = Vector(-cur_dir.1, -cur_dir.0);
+                trace!("BEAM {}, REFLECT (/) => {:?} -> {:?}", beam, cur_pos, cur_dir);
+            },
+            Tile::SplitHorizontal => {
+                match cur_dir {
+                    Vector::RIGHT | Vector::LEFT => (),
+                    Vector::DOWN | Vector::UP => return Some((cur_pos, [Vector::RIGHT, Vector::LEFT])),
+                    _ => panic!("Shouldn't happen")
+                }
+            },
+            Tile::SplitVertical => {
+                match cur_dir {
+                    Vector::RIGHT | Vector::LEFT => return Some((cur_pos, [Vector::DOWN, Vector::UP])),
+                    Vector::DOWN | Vector::UP => (),
+                    _ => panic!("Shouldn't happen")
+                }
+            }
+        }
+    }
+    None
+}
+
+fn get_energized_cells(grid: &Grid, start: GridPos, start_dir: Vec2) -> usize {
+
+    let mut grid = grid.clone();
+    grid[start].energized = true;
+    let mut start_queue = VecDeque::from([(start, start_dir)]);
+    let mut visited = vec![];
+    let mut beam = 0usize;
+    while !start_queue.is_empty() {
+        let start = start_queue.pop_front().unwrap();
+        if visited.contains(&start) {
+            continue;
+        }
+        let continuation = trace_beam(&mut grid, start.0, start.1, beam);
+        visited.push(start);
+        if let Some(c) = continuation {
+            trace!("SPLIT: ({:?}) <- {:?} -> ({:?})", c.1[0], c.0, c.1[1]);
+            start_queue.push_back((c.0, c.1[0])); 
+            start_queue.push_back((c.0, c.1[1])); 
+        }
+        beam += 1;
+    }
+
+    grid
+        .iter()
+        .filter(|(_, c)| c.energized)
+        .count()
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let grid = Grid::try_parse(input, GridCell::new)?;
+
+
+    let mut max_beams = 0usize;
+    let width = grid.width();
+    let height = grid.height();
+    for y in 0..height {
+        for x in 0..width {
+            if y > 0 && y < height-1 && x > 0 && x < width-1 {
+                continue;
+            }
+            let start_dir = match (x,y) {
+                (_, 0) => Vector::UP,
+                (_, h) if h == height-1  => Vector::DOWN,
+                (0, _) => Vector::RIGHT,
+                (w, _) if w == width -1 => Vector::LEFT,
+                _ => panic!("undesired start position")
+            };
+            trace!("Doing ({},{})", x, y);
+            max_beams = std::cmp::max(max_beams, get_energized_cells(&grid, Point(x, y), start_dir));
+        }
+    }
+    Ok(max_beams)
+}
@@ -0,0 +1,199 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use core::fmt::Formatter;
+
+use aoc_common::{stats, ParseError};
+use grid::{Grid, Point, Vector};
+use tracing::{debug, trace, Level};
+
+#[derive(Debug, Clone)]
+struct Node {
+    coord: Point,
+    prev: (Option<Vector>, usize),
+    // for debugging
+    symbol: Option<char>,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.coord == other.coord && self.prev == other.prev
+    }
+}
+impl Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coord.hash(state);
+        self.prev.hash(state);
+    }
+}
+
+impl Eq for Node {}
+
+impl Node {
+    fn new(coord: &Point) -> Self {
+        Self {
+            coord: *coord,
+            symbol: None,
+            prev: (None, 0)
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Node({})", self.coord)
+    }
+}
+
+type Map = Grid<u32>;
+
+const OFFSETS: [Vector; 4] = Vector::ORTHOGONAL;
+
+fn get_neighbors<const MIN: usize, const MAX: usize>(my_node: &Node, map: &Map) -> Vec<Node> {
+    let mut ret = vec![];
+
+    let forbidden_dir: Option<Vector> = if my_node.prev.1 == MAX {
+        my_node.prev.0
+    } else {
+        None
+    };
+    if forbidden_dir.is_some() {
+        trace!("Forbidden dir: {:?}", forbidden_dir);
+    }
+
+    let offsets = match my_node.prev.0 {
+        Some(dir) if my_node.prev.1 < MIN => vec![ dir ],
+        _ => OFFSETS.to_vec()
+    };
+
+    for offset in &offsets {
+        let Some(neighbor) = map.step(my_node.coord, *offset) else {
+            continue;
+        };
+        if (my_node.prev.0.is_some() && my_node.prev.0.unwrap() == -*offset)
+            || (forbidden_dir.is_some() && *offset == forbidden_dir.unwrap())
+        {
+            continue;
+        }
+        let mut neighbor_node = my_node.clone();
+        neighbor_node.coord = neighbor;
+        if let Some(prev) = &my_node.prev.0 {
+            if *prev != *offset {
+                neighbor_node.prev.0 = Some(*offset);
+                neighbor_node.prev.1 = 1;
+            } else {
+                neighbor_node.prev.1 += 1;
+            }
+        } else {
+            neighbor_node.prev.0 = Some(*offset);
+            neighbor_node.prev.1 = 1;
+        }
+        ret.push(neighbor_node);
+    }
+    ret
+}
+
+fn heat_loss(p: &Point, map: &Map) -> usize {
+    map[*p] as usize
+}
+
+fn find_path(map: &Map) -> Option<Vec<Node>> {
+
+    let start = Point(0usize, 0usize);
+    let dest = Point(map.width()-1, map.height()-1);
+    let h = |coord: &Point| coord.manhattan(dest);
+
+    let mut g_costs: HashMap<Node, usize> = HashMap::new();
+    let mut f_costs: HashMap<Node, usize> = HashMap::new();
+    let mut came_from: HashMap<Node, Node> = HashMap::new();
+
+    g_costs.insert(Node::new(&start), 0);
+    f_costs.insert(Node::new(&start),  h(&start));
+
+    let mut open_set = PriorityQueue::new();
+    open_set.push(Node::new(&start), Reverse(h(&start)));
+
+    while !open_set.is_empty() {
+        let current = open_set.pop().unwrap().0;
+        trace!("({},{})", current.coord.0, current.coord.1);
+        // Arrived at destination
+        if current.coord == dest {
+            let mut ret = vec![ current.clone() ];
+            let mut cur = &current;
+            while let Some(tmp) = came_from.get(cur) {
+                if tmp.coord != start {
+                    ret.push(tmp.clone());
+                }
+                cur = tmp;
+            }
+            return Some(ret);
+        }
+
+        // Process neighbors
+        for mut neighbor in get_neighbors::<4, 10>(&current, map) {
+            trace!("\t: {}, {:?} ", neighbor.coord, neighbor.prev);
+            let tentative_g_cost = g_costs.get(&current).unwrap() + heat_loss(&neighbor.coord, map);
+
+            if tentative_g_cost < *g_costs.get(&neighbor).unwrap_or(&usize::MAX) {
+                trace!("g: {} < {}", tentative_g_cost, *g_costs.get(&neighbor).unwrap_or(&usize::MAX));
+
+                let dir_to_neighbor = neighbor.coord - current.coord;
+                neighbor.symbol = match dir_to_neighbor {
+                    Vector(-1, 0) => Some('<'),
+                    Vector(0, -1) => Some('^'),
+                    Vector(1, 0) => Some('>'),
+                    Vector(0, 1) => Some('v'),
+                    _ => panic!("Shouldn't happen")
+                };
+                g_costs.insert(neighbor.clone(), tentative_g_cost);
+                f_costs.insert(neighbor.clone(), tentative_g_cost + h(&neighbor.coord));
+                came_from.insert(neighbor.clone(), current.clone());
+                trace!("f: {}", f_costs[&neighbor]);
+                if !open_set.iter().any(|p| *p.0 == neighbor) {
+                    open_set.push(neighbor.clone(), Reverse(f_costs[&neighbor]));
+                }
+            } else {
+                trace!("g: {} >= {}", tentative_g_cost, g_costs[&neighbor]);
+            }
+        }
+    }
+    None
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let map: Map = Grid::try_parse(input, |ch| ch.to_digit(10))?;
+
+    let path = find_path(&map)
+        .ok_or_else(|| ParseError::at(map.height(), map.width(), "a way to the bottom right", "no path"))?;
+
+    if tracing::enabled!(Level::TRACE) {
+        let mut out = String::new();
+        for (row_idx,row) in map.rows().enumerate() {
+            for col_idx in 0..row.len() {
+                if let Some(node) = path.iter().find(|p| p.coord == Point(col_idx,row_idx)) {
+                    out.push(node.symbol.unwrap());
+                } else {
+                    out += &heat_loss(&Point(col_idx, row_idx), &map).to_string();
+                }
+            }
+            out.push('\n');
+        }
+        trace!("\n{}", out);
+    }
+    debug!("Length of path: {}", path.len());
+    stats::record("path_length", path.len());
+    Ok(path.iter().map(|p| heat_loss(&p.coord, &map)).sum::<usize>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_maps() {
+        assert_eq!(solve("24\n3x\n").unwrap_err().to_string(), "line 2, column 2: expected grid cell, found 'x'");
+        assert_eq!(solve("").unwrap_err().expected, "at least one row");
+    }
+}
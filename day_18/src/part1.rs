@@ -0,0 +1,271 @@
+use aoc_common::{ParseError, Scanner};
+use std::collections::VecDeque;
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone)]
+enum Direction {
+    Up(usize),
+    Down(usize),
+    Right(usize),
+    Left(usize),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+struct Instruction {
+    dig: Direction,
+    color: String
+}
+
+impl Instruction {
+    // R 6 (#70c710)
+    fn new(s: &str) -> Result<Self, ParseError> {
+        use Direction::*;
+        let mut s = Scanner::new(s);
+        let dir = s.one_of(&[("R", Right as fn(usize) -> Direction), ("L", Left), ("U", Up), ("D", Down)])?;
+        s.tag(" ")?;
+        let num = s.unsigned()?;
+        s.tag(" (#")?;
+        let color = parse_color(&mut s)?;
+        s.tag(")")?;
+        s.end()?;
+        Ok(Self {
+            dig: dir(num),
+            color: color.to_owned()
+        })
+    }
+}
+
+fn parse_color<'a>(s: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+    let start = *s;
+    let color = s.take_while(|c| c.is_ascii_hexdigit());
+    if color.len() != 6 {
+        return Err(start.error("6 hex digits"));
+    }
+    Ok(color)
+}
+
+type FieldDim = ((isize, isize), (isize,isize));
+
+/// Most pixels part 1 draws, anything bigger is for part 2's way of counting.
+const MAX_PIXELS: usize = 1 << 30;
+
+/// Fails with the index of the first instruction digging too far.
+fn get_dims(instr: &[Instruction]) -> Result<FieldDim, usize> {
+    use Direction::*;
+    // The start is dug too
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (0isize, 0isize, 0isize, 0isize);
+    let mut x: isize = 0;
+    let mut y: isize = 0;
+    for (idx, i) in instr.iter().enumerate() {
+        //println!("At ({},{})", x, y);
+        let moved = match i.dig {
+            Left(n) => x.checked_sub_unsigned(n).map(|to| x = to),
+            Right(n) => x.checked_add_unsigned(n).map(|to| x = to),
+            Up(n) => y.checked_sub_unsigned(n).map(|to| y = to),
+            Down(n) => y.checked_add_unsigned(n).map(|to| y = to)
+        };
+        if moved.is_none() {
+            return Err(idx);
+        }
+        min_x = std::cmp::min(min_x, x);
+        max_x = std::cmp::max(max_x, x);
+        min_y = std::cmp::min(min_y, y);
+        max_y = std::cmp::max(max_y, y);
+        let width = max_x.abs_diff(min_x).saturating_add(1);
+        let height = max_y.abs_diff(min_y).saturating_add(1);
+        if width.saturating_mul(height) > MAX_PIXELS {
+            return Err(idx);
+        }
+    }
+    Ok(((min_x, max_x), (min_y, max_y)))
+}
+
+fn normalize_coord(coord: (isize, isize), dims: FieldDim) -> (usize, usize) {
+    ((coord.0 + dims.0.0.abs()) as usize, (coord.1 + dims.1.0.abs()) as usize)
+}
+
+fn draw_map(instr: &[Instruction], map: &mut [Vec<char>], dims: FieldDim) {
+    use Direction::*;
+
+    let mut x = 0isize;
+    let mut y = 0isize;
+    for i in instr {
+        match i.dig {
+            Left(n) | Right(n) => {
+                //println!("{:?}", i.dig);
+                let step;
+                let to_x = if let Left(_) = i.dig {
+                    let tmp = x.checked_sub_unsigned(n).unwrap();
+                    step = -1isize;
+                    tmp
+                } else {
+                    let tmp = x.checked_add_unsigned(n).unwrap();
+                    step = 1isize;
+                    tmp
+                };
+                while x != to_x {
+                    let (tmp_x,tmp_y) = normalize_coord((x,y), dims);
+                    map[tmp_y][tmp_x] = '#'; 
+                    x = x.checked_add(step).unwrap();
+                }
+            },
+            Up(n) | Down(n) => {
+                //println!("{:?}", i.dig);
+                let step;
+                let to_y = if let Up(_) = i.dig {
+                    let tmp = y.checked_sub_unsigned(n).unwrap();
+                    step = -1isize;
+                    tmp
+                } else {
+                    let tmp = y.checked_add_unsigned(n).unwrap();
+                    step = 1isize;
+                    tmp
+                };
+                while y != to_y {
+                    let (tmp_x,tmp_y) = normalize_coord((x,y), dims);
+                    map[tmp_y][tmp_x] = '#'; 
+                    y = y.checked_add(step).unwrap();
+                }
+            },
+        };
+
+    }
+}
+
+fn find_fill_start_point(map: &[Vec<char>]) -> Option<(usize, usize)> {
+    for (line_idx,line) in map.iter().enumerate() {
+        // An open trench can leave rows it never crosses
+        let (Some(mut first), Some(last)) = (line.iter().position(|&c| c == '#'), line.iter().rposition(|&c| c == '#')) else {
+            continue;
+        };
+        while first < last {
+            if line[first] == '.' {
+                return Some((first, line_idx));
+            }
+            first += 1;
+        }
+    }
+    None
+}
+
+const OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1)
+];
+
+fn flood_fill(map: &mut [Vec<char>], start: (usize,usize)) {
+    let mut the_queue = VecDeque::new();
+    the_queue.push_back(start);
+    while !the_queue.is_empty() {
+        let p = the_queue.pop_front().unwrap();
+        if map[p.1][p.0] == '#' {
+            continue;
+        }
+        
+        //println!("---------------------------------");
+        //println!("At ({},{})", p.0, p.1);
+        // Process
+        map[p.1][p.0] = '#';
+
+
+        // Find neighbors
+        for offset in OFFSETS {
+            let neighbor = (p.0.checked_add_signed(offset.0), p.1.checked_add_signed(offset.1));
+            if neighbor.0.is_none() || neighbor.1.is_none()
+                || neighbor.0.unwrap() > map.first().unwrap().len()-1
+                || neighbor.1.unwrap() > map.len()-1
+                || map[neighbor.1.unwrap()][neighbor.0.unwrap()] == '#'
+            {
+                continue;
+            }
+            the_queue.push_back((neighbor.0.unwrap(), neighbor.1.unwrap()));
+            //println!("\tAdding ({},{})", neighbor.0.unwrap(), neighbor.1.unwrap());
+        }
+    }
+}
+
+fn print_map(map: &[Vec<char>]) {
+    trace!("\n{}", map.iter().map(|line| line.iter().collect::<String>()).collect::<Vec<_>>().join("\n"));
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let instr = input
+        .lines()
+        .map(Instruction::new)
+        .collect::<Result<Vec<Instruction>, _>>()
+        .map_err(|e| e.locate(input))?;
+    if instr.is_empty() {
+        return Err(ParseError::end_of_input("a dig instruction", input));
+    }
+    
+    /*
+    for i in &instr {
+        println!("{:?}", i);
+    }
+    */
+
+    let dims = get_dims(&instr).map_err(|idx| {
+        // The distance is the second field
+        let line = input.lines().nth(idx).unwrap_or_default();
+        let distance = line.split(' ').nth(1).unwrap_or(line);
+        ParseError::new("a distance keeping the map small enough to draw", distance).locate(input)
+    })?;
+    let ((min_x, max_x), (min_y, max_y)) = dims;
+
+    let mut map = vec![vec!['.'; (max_x+min_x.abs()+1) as usize]; (max_y+min_y.abs()+1) as usize];
+
+    debug!("{}x{} pixels", map.first().unwrap().len(), map.len());
+
+
+    draw_map(&instr, &mut map, dims);
+
+
+    //print_map(&map);
+
+    let start = find_fill_start_point(&map);
+    debug!("Start point: {:?}", start);
+
+    // A trench without an inside has nothing to fill
+    if let Some(start) = start {
+        flood_fill(&mut map, start);
+    }
+
+    debug!("Done filling.");
+    print_map(&map);
+
+    Ok(map
+        .iter()
+        .flatten()
+        .fold(0usize, |acc,x| acc + match x {
+            '#' => 1,
+            '.' => 0,
+            _ => panic!("Can't happen")
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_instructions() {
+        assert!(Instruction::new("R 6 (#70c710)").is_ok());
+        assert_eq!(Instruction::new(",R 6 (#70c710)").unwrap_err().expected, "R, L, U or D");
+        assert_eq!(Instruction::new("R,6 (#70c710)").unwrap_err().found, ",6");
+        assert_eq!(Instruction::new("R 6 (#70c71)").unwrap_err().expected, "6 hex digits");
+        assert_eq!(Instruction::new("R 6 (#70c710) ").unwrap_err().expected, "end of line");
+        assert_eq!(solve("").unwrap_err().to_string(), "line 1, column 1: expected a dig instruction, found end of input");
+        assert_eq!(solve("R 2 (#70c710)\nL 2 (#70c710)\n"), Ok(3));
+        assert_eq!(solve("D 1 (#70c710)\n"), Ok(1));
+        let err = solve("R 5 (#70c710)\nR 18446744073709551615 (#70c710)\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "18446744073709551615"));
+        assert_eq!(solve("R 100000 (#70c710)\nD 100000 (#70c710)\n").unwrap_err().line, 2);
+    }
+}
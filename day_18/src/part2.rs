@@ -0,0 +1,170 @@
+use aoc_common::{ParseError, Scanner};
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone)]
+enum Direction {
+    Up(usize),
+    Down(usize),
+    Right(usize),
+    Left(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    dig: Direction,
+}
+
+impl Instruction {
+    // R 6 (#70c710), only the colour matters: 5 hex digits of distance
+    // followed by the direction
+    fn new(s: &str) -> Result<Self, ParseError> {
+        use Direction::*;
+        let mut s = Scanner::new(s);
+        s.one_of(&[("R", ()), ("L", ()), ("U", ()), ("D", ())])?;
+        s.tag(" ")?;
+        s.unsigned::<usize>()?;
+        s.tag(" (#")?;
+        let color = parse_color(&mut s)?;
+        s.tag(")")?;
+        s.end()?;
+        let num = usize::from_str_radix(&color[..5], 16).unwrap();
+        Ok(Self {
+            dig: match &color[5..6] {
+                "0" => Right(num),
+                "1" => Down(num),
+                "2" => Left(num),
+                "3" => Up(num),
+                _ => return Err(ParseError::new("direction digit 0-3", &color[5..6]))
+            },
+        })
+    }
+}
+
+fn parse_color<'a>(s: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+    let start = *s;
+    let color = s.take_while(|c| c.is_ascii_hexdigit());
+    if color.len() != 6 {
+        return Err(start.error("6 hex digits"));
+    }
+    Ok(color)
+}
+
+type FieldDim = ((isize, isize), (isize,isize));
+
+fn get_dims(instr: &[Instruction], vertices: &mut Vec<(isize,isize)>) -> FieldDim {
+    use Direction::*;
+    let mut min_x = isize::MAX;
+    let mut max_x = isize::MIN;
+    let mut min_y = isize::MAX;
+    let mut max_y = isize::MIN;
+    let mut x: isize = 0;
+    let mut y: isize = 0;
+    vertices.push((x,y));
+    for i in instr {
+        //println!("At ({},{})", x, y);
+        match i.dig {
+            Left(n) => x = x.checked_sub_unsigned(n).unwrap(),
+            Right(n) => x = x.checked_add_unsigned(n).unwrap(),
+            Up(n) => y = y.checked_sub_unsigned(n).unwrap(),
+            Down(n) => y = y.checked_add_unsigned(n).unwrap()
+        };
+        min_x = std::cmp::min(min_x, x);
+        max_x = std::cmp::max(max_x, x);
+        min_y = std::cmp::min(min_y, y);
+        max_y = std::cmp::max(max_y, y);
+
+        vertices.push((x,y));
+    }
+    ((min_x, max_x), (min_y, max_y))
+}
+
+#[allow(dead_code)]
+fn calc_area(instr: &[Instruction], height: usize) -> u128 {
+    use Direction::*;
+
+    let mut cur_height = height;
+    let mut area = height as i128;
+
+    let dir = match instr.first().unwrap().dig {
+        Left(_) => -1i128,
+        Right(_) => 1i128,
+        _ => panic!("Oops")
+    };
+
+    for i in instr {
+        match i.dig {
+            Right(n) => {
+                let amount = (n * cur_height) as i128;
+                trace!("Right n = {}: Height: {}, Area: {}, amount = {}", n, cur_height, area, amount);
+                area = area.checked_add(dir * amount).unwrap();
+            },
+            Down(n) => {
+                cur_height -= n;
+                trace!("Down n = {}: Height: {}, Area: {}", n, cur_height, area);
+            },
+            Left(n) => {
+                // -1 cos we only need to subtract what's OUTSIDE!
+                let amount = (n * (cur_height-1)) as i128;
+                trace!("Left n = {}: Height: {}, Area: {}, amount = {}", n, cur_height, area, amount);
+                area = area.checked_add(-dir * amount).unwrap();
+            },
+            Up(n) => {
+                cur_height += n;
+                trace!("Up n = {}: Height: {}, Area: {}", n, cur_height, area);
+            }
+        }
+    }
+
+    area as u128
+}
+
+fn shoelace(vertices: &[(isize,isize)]) -> usize {
+    let mut area = vertices
+        .windows(2)
+        .map(|p| (p[0].0*p[1].1 - p[0].1*p[1].0)
+            // Add edges
+            + (p[0].0 - p[1].0).abs() 
+            + (p[0].1 - p[1].1).abs())
+        .sum::<isize>();
+
+    area += (vertices.last().unwrap().0*vertices.first().unwrap().1 
+        - vertices.last().unwrap().1*vertices.first().unwrap().0).abs();
+
+    // Also add start pixel
+    (area / 2 + 1) as usize
+}
+
+#[allow(dead_code)]
+fn print_map(map: &[Vec<char>]) {
+    trace!("\n{}", map.iter().map(|line| line.iter().collect::<String>()).collect::<Vec<_>>().join("\n"));
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let instr = input
+        .lines()
+        .map(Instruction::new)
+        .collect::<Result<Vec<Instruction>, _>>()
+        .map_err(|e| e.locate(input))?;
+    if instr.is_empty() {
+        return Err(ParseError::end_of_input("a dig instruction", input));
+    }
+    
+    for i in &instr {
+        trace!("{:?}", i);
+    }
+
+    let mut vertices: Vec<(isize,isize)> = Vec::new();
+
+    let dims = get_dims(&instr, &mut vertices);
+    let ((min_x, max_x), (min_y, max_y)) = dims;
+    let width = (max_x + min_x.abs() + 1) as usize;
+    let height = (max_y + min_y.abs() + 1) as usize;
+
+    debug!("{}x{} pixels", width, height);
+
+
+    for p in &vertices {
+        trace!("({},{})", p.0, p.1);
+    }
+    Ok(shoelace(&vertices))
+}
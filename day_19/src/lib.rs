@@ -0,0 +1,19 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Part1 = u32;
+    type Part2 = u128;
+
+    fn solve_part1(input: &str) -> Result<u32, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<u128, ParseError> {
+        part2::solve(input)
+    }
+}
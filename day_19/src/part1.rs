@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+use aoc_common::{ParseError, Scanner};
+use tracing::{debug, trace};
+
+#[derive(Debug)]
+enum Rule {
+    LessThan((Category,u32,String)),
+    GreaterThan((Category,u32,String)),
+    Accept,
+    Reject
+}
+
+impl Rule {
+    fn process(&self, p: &Part) -> Option<&str> {
+        use Rule::*;
+        match self {
+            Accept => Some("A"),
+            Reject => Some("R"),
+            LessThan((cat,val,to)) => if p.categories[cat] < *val {
+                Some(to)
+            } else {
+                None
+            }
+            GreaterThan((cat,val,to)) => if p.categories[cat] > *val {
+                Some(to)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl Rule {
+    // a<2006:qkq, or a bare A/R
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        if s.eat("A") {
+            return Ok(Rule::Accept);
+        }
+        if s.eat("R") {
+            return Ok(Rule::Reject);
+        }
+        let cat = Category::parse(s)?;
+        let less = s.one_of(&[("<", true), (">", false)])?;
+        let val = s.unsigned()?;
+        s.tag(":")?;
+        let next = s.word()?;
+
+        let op_val_tup = (cat, val, next.to_owned());
+        if less {
+            Ok(Rule::LessThan(op_val_tup))
+        } else {
+            Ok(Rule::GreaterThan(op_val_tup))
+        }
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S
+}
+
+impl Category {
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.one_of(&[("x", Category::X), ("m", Category::M), ("a", Category::A), ("s", Category::S)])
+    }
+}
+
+#[derive(Debug)]
+struct Part {
+    categories: HashMap<Category, u32> 
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    // {x=787,m=2655,a=1222,s=2876}
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(value);
+        s.tag("{")?;
+        let categories = s.list(",", |s| {
+            let cat = Category::parse(s)?;
+            s.tag("=")?;
+            Ok((cat, s.unsigned()?))
+        })?;
+        s.tag("}")?;
+        s.end()?;
+        Ok(Self {
+            categories: categories.into_iter().collect()
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    final_dest: String
+}
+
+impl Workflow {
+    fn process(&self, p: &Part) -> &str {
+        for rule in &self.rules {
+            if let Some(to) = rule.process(p) {
+                return to;
+            }
+        }
+        self.final_dest.as_str()
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    // px{a<2006:qkq,m>2090:A,rfg}
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(value);
+        let name = s.take_while1("workflow name", |c| c.is_ascii_lowercase())?;
+        s.tag("{")?;
+        let mut rules = vec![];
+        // Everything but the last entry is a condition like `a<2006:qkq`
+        while matches!(s.rest().as_bytes().get(1), Some(b'<' | b'>')) {
+            rules.push(Rule::parse(&mut s)?);
+            s.tag(",")?;
+        }
+        let final_dest = s.word()?;
+        s.tag("}")?;
+        s.end()?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            rules,
+            final_dest: final_dest.to_owned()
+        })
+    }
+}
+
+impl Workflow {
+    /// Everywhere this workflow can send a part, `A` and `R` included.
+    fn destinations(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Rule::LessThan((_,_,to)) | Rule::GreaterThan((_,_,to)) => Some(to.as_str()),
+                _ => None
+            })
+            .chain(std::iter::once(self.final_dest.as_str()))
+    }
+}
+
+/// Following the workflows starts at `in` and must never reach one that
+/// doesn't exist.
+fn check_destinations(contents: &str, workflows: &[(&str, Workflow)]) -> Result<(), ParseError> {
+    let names = workflows.iter().map(|(_, w)| w.name.as_str()).collect::<HashSet<_>>();
+    if !names.contains("in") {
+        return Err(ParseError::end_of_input("workflow in", contents));
+    }
+    for (line, w) in workflows {
+        for dest in w.destinations() {
+            if dest == "A" || dest == "R" || names.contains(dest) {
+                continue;
+            }
+            // Point at the mention after a ':' or ',', not at some other name containing it
+            let bytes = line.as_bytes();
+            let at = line
+                .match_indices(dest)
+                .map(|(idx, _)| idx)
+                .find(|&idx| idx > 0
+                    && matches!(bytes[idx - 1], b':' | b',' | b'{')
+                    && matches!(bytes.get(idx + dest.len()), Some(b',' | b'}')))
+                .unwrap_or(0);
+            return Err(ParseError::new("a defined workflow", &line[at..at + dest.len()]).locate(contents));
+        }
+    }
+    Ok(())
+}
+
+impl Hash for Workflow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+pub fn solve(contents: &str) -> Result<u32, ParseError> {
+
+    let idx_separator = contents
+        .lines()
+        .position(|l| l.is_empty())
+        .ok_or_else(|| ParseError::new("blank line between workflows and parts", &contents[contents.len()..]).locate(contents))?;
+
+    debug!("Separator at {}", idx_separator);
+
+    let parsed = contents
+        .lines()
+        .take(idx_separator)
+        .map(|l| Workflow::from_str(l).map(|w| (l, w)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.locate(contents))?;
+    check_destinations(contents, &parsed)?;
+    let workflows = parsed
+        .into_iter()
+        .map(|(_, w)| (w.name.clone(), w))
+        .collect::<HashMap<String, Workflow>>();
+        
+    for w in &workflows {
+        trace!("{:?}", w);
+    }
+
+    let parts = contents.lines()
+        .skip(idx_separator+1)
+        .map(Part::from_str)
+        .collect::<Result<Vec<Part>, _>>()
+        .map_err(|e| e.locate(contents))?;
+
+
+    for p in &parts {
+        trace!("{:?}", p);
+    }
+
+
+    let mut accepted = vec![];
+    for p in &parts {
+        let mut cur_workflow = "in";
+        while cur_workflow != "A" && cur_workflow != "R" {
+            let workflow = workflows.get(cur_workflow).unwrap();
+            cur_workflow = workflow.process(p);
+        }
+        if cur_workflow == "A" {
+            accepted.push(p);
+        }
+    }
+
+    Ok(accepted
+        .iter()
+        .map(|&p| p.categories.values().sum::<u32>())
+        .sum::<u32>())
+}
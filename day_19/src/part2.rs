@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use aoc_common::{stats, ParseError, Scanner};
+use std::fmt::Display;
+use tracing::{debug, trace};
+
+#[derive(Debug)]
+enum Rule {
+    LessThan((Category,u32,String)),
+    GreaterThan((Category,u32,String)),
+    Accept,
+    Reject
+}
+
+impl Rule {
+    fn adapt_range(&self, coll: &mut PartCollection, coll_inv: &mut PartCollection) -> Option<&str> {
+        use Rule::*;
+        match self {
+            Accept => return Some("A"),
+            Reject=> return Some("R"),
+            _ => {}
+        };
+        let (range, inv_range) = match self {
+            LessThan((cat,_,_)) | GreaterThan((cat,_,_)) => {
+                (coll.ranges.get_mut(cat).unwrap(), coll_inv.ranges.get_mut(cat).unwrap())
+            },
+            _ => panic!("Impossible")
+        };
+        let (my_range, my_inv_range, to) = match self {
+            LessThan((_,val,to)) => (1..=val.saturating_sub(1), *val..=4000, to),
+            GreaterThan((_,val,to)) => (val.saturating_add(1)..=4000, 1..=*val, to),
+            _ => panic!("Impossible")
+        };
+        if (range.start() < my_range.start() && range.end() < my_range.start())
+            || (range.start() > my_range.end() && range.end() > my_range.end()) {
+            return None;
+        }
+        *inv_range = RangeInclusive::new(*std::cmp::max(range.start(), my_inv_range.start()),
+            *std::cmp::min(range.end(), my_inv_range.end()));
+        *range = RangeInclusive::new(*std::cmp::max(range.start(), my_range.start()),
+            *std::cmp::min(range.end(), my_range.end()));
+
+        Some(to)
+    }
+}
+
+impl Rule {
+    // a<2006:qkq, or a bare A/R
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        if s.eat("A") {
+            return Ok(Rule::Accept);
+        }
+        if s.eat("R") {
+            return Ok(Rule::Reject);
+        }
+        let cat = Category::parse(s)?;
+        let less = s.one_of(&[("<", true), (">", false)])?;
+        let val = s.unsigned()?;
+        s.tag(":")?;
+        let next = s.word()?;
+
+        let op_val_tup = (cat, val, next.to_owned());
+        if less {
+            Ok(Rule::LessThan(op_val_tup))
+        } else {
+            Ok(Rule::GreaterThan(op_val_tup))
+        }
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S
+}
+
+impl Category {
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.one_of(&[("x", Category::X), ("m", Category::M), ("a", Category::A), ("s", Category::S)])
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    final_dest: String
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    // px{a<2006:qkq,m>2090:A,rfg}
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(value);
+        let name = s.take_while1("workflow name", |c| c.is_ascii_lowercase())?;
+        s.tag("{")?;
+        let mut rules = vec![];
+        // Everything but the last entry is a condition like `a<2006:qkq`
+        while matches!(s.rest().as_bytes().get(1), Some(b'<' | b'>')) {
+            rules.push(Rule::parse(&mut s)?);
+            s.tag(",")?;
+        }
+        let final_dest = s.word()?;
+        s.tag("}")?;
+        s.end()?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            rules,
+            final_dest: final_dest.to_owned()
+        })
+    }
+}
+
+impl Workflow {
+    /// Everywhere this workflow can send a part, `A` and `R` included.
+    fn destinations(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Rule::LessThan((_,_,to)) | Rule::GreaterThan((_,_,to)) => Some(to.as_str()),
+                _ => None
+            })
+            .chain(std::iter::once(self.final_dest.as_str()))
+    }
+}
+
+/// Following the workflows starts at `in` and must never reach one that
+/// doesn't exist.
+fn check_destinations(contents: &str, workflows: &[(&str, Workflow)]) -> Result<(), ParseError> {
+    let names = workflows.iter().map(|(_, w)| w.name.as_str()).collect::<HashSet<_>>();
+    if !names.contains("in") {
+        return Err(ParseError::end_of_input("workflow in", contents));
+    }
+    for (line, w) in workflows {
+        for dest in w.destinations() {
+            if dest == "A" || dest == "R" || names.contains(dest) {
+                continue;
+            }
+            // Point at the mention after a ':' or ',', not at some other name containing it
+            let bytes = line.as_bytes();
+            let at = line
+                .match_indices(dest)
+                .map(|(idx, _)| idx)
+                .find(|&idx| idx > 0
+                    && matches!(bytes[idx - 1], b':' | b',' | b'{')
+                    && matches!(bytes.get(idx + dest.len()), Some(b',' | b'}')))
+                .unwrap_or(0);
+            return Err(ParseError::new("a defined workflow", &line[at..at + dest.len()]).locate(contents));
+        }
+    }
+    Ok(())
+}
+
+impl Hash for Workflow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl Display for PartCollection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rng_x = &self.ranges[&Category::X];
+        let rng_m = &self.ranges[&Category::M];
+        let rng_a = &self.ranges[&Category::A];
+        let rng_s = &self.ranges[&Category::S];
+        f.write_fmt(format_args!("x: [{},{}], m: [{},{}], a: [{},{}], s: [{},{}]",
+            rng_x.start(), rng_x.end(),
+            rng_m.start(), rng_m.end(),
+            rng_a.start(), rng_a.end(),
+            rng_s.start(), rng_s.end()))
+    }
+}
+
+#[derive(Clone)]
+struct PartCollection {
+    ranges: HashMap<Category, std::ops::RangeInclusive<u32>>
+}
+
+impl PartCollection {
+    fn new() -> Self {
+        let mut ranges = HashMap::new();
+        ranges.insert(Category::X, 1..=4000);
+        ranges.insert(Category::M, 1..=4000);
+        ranges.insert(Category::A, 1..=4000);
+        ranges.insert(Category::S, 1..=4000);
+        Self {
+            ranges
+        }
+    }
+
+    fn get_combination_count(&self) -> u128 {
+        self.ranges.values().fold(1, |acc,x| acc * (x.end() - x.start() + 1) as u128)
+    }
+}
+
+fn fit_ranges(workflows: &HashMap<String, Workflow>) -> Vec<PartCollection> {
+    let mut ret = vec![];
+
+    let mut the_stack = vec![ ("in", "", PartCollection::new()) ];
+    while let Some((wf_name, from, coll)) = the_stack.pop() {
+        trace!("{}->{}, {}", from, wf_name, coll);
+        if wf_name == "R" {
+            continue;
+        } else if wf_name == "A" {
+            ret.push(coll); 
+            continue;
+        }
+
+        let wf = workflows.get(wf_name).unwrap();
+        // inv_coll is the "inverted range" of what is passed into the next
+        // processing state, i.e. if s < 400 is passed onto the next stage,
+        // we only have s >= 400 for the next the rule. inv_coll is that s >= 400
+        let mut inv_coll = coll.clone();
+        for rule in &wf.rules {
+            let mut this_coll = inv_coll.clone();
+            let nxt = rule.adapt_range(&mut this_coll, &mut inv_coll);
+            if let Some(nxt) = nxt {
+                the_stack.push((nxt, wf_name, this_coll));
+            }
+        }
+        the_stack.push((&wf.final_dest, wf_name, inv_coll.clone()));
+    }
+    ret
+}
+
+pub fn solve(contents: &str) -> Result<u128, ParseError> {
+
+    // Parts aren't needed here, so don't insist on them being there
+    let parsed = contents
+        .lines()
+        .take_while(|l| !l.is_empty())
+        .map(|l| Workflow::from_str(l).map(|w| (l, w)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.locate(contents))?;
+    check_destinations(contents, &parsed)?;
+    let workflows = parsed
+        .into_iter()
+        .map(|(_, w)| (w.name.clone(), w))
+        .collect::<HashMap<String, Workflow>>();
+        
+    for w in &workflows {
+        trace!("{:?}", w);
+    }
+
+
+    let combos = fit_ranges(&workflows);
+    debug!("Got {} ranges", combos.len()); 
+    stats::record("ranges", combos.len());
+    Ok(combos.iter().fold(0, |acc,x| acc + x.get_combination_count()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calc_combinations() {
+        let coll = PartCollection::new();
+        assert_eq!(coll.get_combination_count(), 256000000000000);
+    }
+
+    #[test]
+    fn test_parse_workflow() {
+        let w: Workflow = "px{a<2006:qkq,m>2090:A,rfg}".parse().unwrap();
+        assert_eq!(w.rules.len(), 2);
+        assert_eq!(w.final_dest, "rfg");
+        assert!("px{a<2006:qkq,,rfg}".parse::<Workflow>().is_err());
+        assert!("px{a=2006:qkq,rfg}".parse::<Workflow>().is_err());
+        assert!("px{a<2006:qkq,rfg}x".parse::<Workflow>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_missing_workflows() {
+        assert_eq!(solve("").unwrap_err().to_string(), "line 1, column 1: expected workflow in, found end of input");
+        let err = solve("in{a<2006:qkq,q}\nq{A}\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 11, "qkq"));
+        assert_eq!(solve("in{a<1:A,R}\n"), Ok(0));
+    }
+}
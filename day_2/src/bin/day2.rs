@@ -0,0 +1,145 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use day_2::estimate::{self, Estimate};
+use day_2::game::{colours, parse_games, Bag};
+use day_2::query;
+use day_2::report::Report;
+
+/// Questions about cube games, for any colours
+#[derive(Parser)]
+struct Cli {
+    /// Game log to read
+    #[arg(short, long, default_value = "input.txt")]
+    input: String,
+    #[command(subcommand)]
+    query: Query,
+}
+
+#[derive(Subcommand)]
+enum Query {
+    /// Games that could have been played with the bag, and the sum of their IDs
+    Possible {
+        /// e.g. red=12,green=13,blue=14,purple=3
+        #[arg(short, long, default_value_t = query::default_bag())]
+        bag: Bag,
+    },
+    /// Fewest cubes each game could have been played with, and its power
+    MinBag,
+    /// Bag with the fewest cubes that makes at least this many percent of games possible
+    SmallestBag {
+        #[arg(default_value_t = 100.0)]
+        percent: f64,
+    },
+    /// Most likely bag contents per game and over all games, with 95% intervals
+    Estimate {
+        /// Most cubes of one colour a bag could hold
+        #[arg(short, long, default_value_t = 30)]
+        max: u32,
+        /// Only the estimate over all games
+        #[arg(long)]
+        aggregate: bool,
+    },
+    /// Min/max/mean/histogram per colour, and each game's minimum bag
+    Report {
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Which table to write as CSV
+        #[arg(short, long, value_enum, default_value_t = Table::Colours)]
+        table: Table,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Csv,
+    /// Everything in one object
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Table {
+    /// Statistics per colour, over picks and over games
+    Colours,
+    /// Minimum bag and power per game
+    Games,
+}
+
+fn print_estimates(label: &str, estimates: Result<Vec<Estimate>, String>, max: u32) {
+    match estimates {
+        Ok(estimates) => {
+            let parts = estimates
+                .iter()
+                .map(|e| {
+                    format!(
+                        "{} {} [{}-{}] mean {:.1} [{}-{}]",
+                        e.colour, e.mle, e.confidence.0, e.confidence.1, e.posterior_mean, e.credible.0, e.credible.1
+                    )
+                })
+                .collect::<Vec<_>>();
+            let capped = if estimates.iter().any(|e| e.mle == max) { " (at --max, bigger bags fit better)" } else { "" };
+            println!("{}: {}{}", label, parts.join(", "), capped);
+        }
+        Err(e) => println!("{}: {}", label, e),
+    }
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let cli = Cli::parse();
+    let text = match std::fs::read_to_string(&cli.input) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: {}: {}", cli.input, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let games = match parse_games(&text) {
+        Ok(games) => games,
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(&cli.input));
+            return ExitCode::FAILURE;
+        }
+    };
+    match cli.query {
+        Query::Possible { bag } => {
+            let possible = query::possible(&games, &bag);
+            for game in &possible {
+                println!("Game {}", game.id);
+            }
+            println!("{} of {} games possible, IDs sum to {}", possible.len(), games.len(), possible.iter().map(|g| g.id).sum::<u32>());
+        }
+        Query::MinBag => {
+            let bags = query::min_bags(&games);
+            for (id, bag, power) in &bags {
+                println!("Game {}: {} power {}", id, bag, power);
+            }
+            println!("Sum of powers: {}", bags.iter().map(|(_, _, p)| p).sum::<u64>());
+        }
+        Query::SmallestBag { percent } => {
+            let (bag, fits) = query::smallest_bag(&games, percent);
+            println!("{} ({} cubes) makes {} of {} games possible", bag, bag.total(), fits, games.len());
+        }
+        Query::Estimate { max, aggregate } => {
+            let colours = colours(&games);
+            println!("colour likeliest [{0}% confidence] mean [{0}% credible]", estimate::LEVEL * 100.0);
+            if !aggregate {
+                for game in &games {
+                    print_estimates(&format!("Game {}", game.id), estimate::per_game(game, &colours, max), max);
+                }
+            }
+            print_estimates("All games", estimate::aggregate(&games, &colours, max), max);
+        }
+        Query::Report { format, table } => {
+            let report = Report::new(&games);
+            match (format, table) {
+                (Format::Text, _) => print!("{}", report.text()),
+                (Format::Csv, Table::Colours) => print!("{}", report.colours_csv()),
+                (Format::Csv, Table::Games) => print!("{}", report.games_csv()),
+                (Format::Json, _) => println!("{}", serde_json::to_string_pretty(&report.to_json()).unwrap()),
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,243 @@
+//! What was most likely in the bag, judging by what came out of it.
+//!
+//! Each pick is a handful drawn without replacement and put back afterwards,
+//! so given bag counts `K` a pick showing `k` of each colour (`n` in total)
+//! has probability `prod C(K_c, k_c) / C(sum K, n)`. Colours the pick didn't
+//! show count as 0 of them drawn.
+//!
+//! Every bag from the game's minimum bag up to `max` of each colour is tried.
+//! That gives the maximum likelihood bag with a profile likelihood interval
+//! per colour, and, with a flat prior over the same bags, the posterior mean
+//! with an equal-tailed credible interval.
+
+use crate::game::{Bag, Game, Pick};
+
+/// Both intervals are 95%.
+pub const LEVEL: f64 = 0.95;
+/// Half the 95% quantile of chi-squared with one degree of freedom. Counts
+/// whose profile log-likelihood is within this of the best are in the
+/// confidence interval.
+const HALF_CHI2: f64 = 1.920729;
+/// Refuse to try more bags than this.
+pub const MAX_BAGS: u64 = 5_000_000;
+/// Refuse bags that could hold more cubes than this, `ln(n!)` gets tabulated
+/// up to the biggest.
+pub const MAX_CUBES: u64 = 1 << 22;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub colour: String,
+    pub mle: u32,
+    /// Confidence interval around `mle`, inclusive.
+    pub confidence: (u32, u32),
+    pub posterior_mean: f64,
+    /// Credible interval, inclusive.
+    pub credible: (u32, u32),
+}
+
+/// `ln(n!)` for every `n` up to the table size.
+struct LnFactorial(Vec<f64>);
+
+impl LnFactorial {
+    fn new(n: usize) -> Self {
+        let mut ret = vec![0.0; n + 1];
+        for i in 1..=n {
+            ret[i] = ret[i - 1] + (i as f64).ln();
+        }
+        LnFactorial(ret)
+    }
+
+    fn choose(&self, n: u32, k: u32) -> f64 {
+        self.0[n as usize] - self.0[k as usize] - self.0[(n - k) as usize]
+    }
+}
+
+/// Estimates bag counts of `colours` from `picks`, assuming they all came out
+/// of the same bag.
+pub fn estimate(picks: &[&Pick], colours: &[&str], max: u32) -> Result<Vec<Estimate>, String> {
+    let mut min = Bag::default();
+    for pick in picks {
+        min.extend_to(pick);
+    }
+    let lower = colours.iter().map(|c| min.get(c)).collect::<Vec<_>>();
+    if let Some((colour, need)) = colours.iter().zip(&lower).find(|(_, &need)| need > max) {
+        return Err(format!("needs {} {} cubes, more than the maximum of {}", need, colour, max));
+    }
+    let bags = lower.iter().fold(1u64, |acc, &lo| acc.saturating_mul((max - lo) as u64 + 1));
+    if bags > MAX_BAGS {
+        return Err(format!("{} possible bags is too many, lower the maximum", bags));
+    }
+    let cubes = max as u64 * colours.len() as u64;
+    if cubes > MAX_CUBES {
+        return Err(format!("bags of up to {} cubes are too big, lower the maximum", cubes));
+    }
+
+    // Picks only matter through their counts, so look those up once.
+    let picks = picks
+        .iter()
+        .map(|p| {
+            let counts = colours.iter().map(|c| p.get(c)).collect::<Vec<_>>();
+            let total = counts.iter().sum::<u32>();
+            (counts, total)
+        })
+        .collect::<Vec<_>>();
+    let ln = LnFactorial::new(cubes as usize);
+    let log_likelihood = |bag: &[u32]| {
+        let total = bag.iter().sum();
+        picks
+            .iter()
+            .map(|(counts, n)| {
+                let ways = bag.iter().zip(counts).map(|(&have, &drawn)| ln.choose(have, drawn)).sum::<f64>();
+                ways - ln.choose(total, *n)
+            })
+            .sum::<f64>()
+    };
+
+    // Every bag's log-likelihood, in odometer order.
+    let mut points = vec![];
+    let mut bag = lower.clone();
+    loop {
+        points.push((bag.clone(), log_likelihood(&bag)));
+        let Some(idx) = bag.iter().position(|&n| n < max) else {
+            break;
+        };
+        bag[..idx].copy_from_slice(&lower[..idx]);
+        bag[idx] += 1;
+    }
+    // Ties go to the smaller bag, which comes first. Sums of logs don't tie
+    // exactly, hence the slack.
+    let (best, best_ll) = points.iter().fold((&points[0].0, points[0].1), |(b, ll), (bag, l)| {
+        if *l > ll + 1e-9 {
+            (bag, *l)
+        } else {
+            (b, ll)
+        }
+    });
+
+    let estimates = colours
+        .iter()
+        .enumerate()
+        .map(|(c, colour)| {
+            let values = (max - lower[c]) as usize + 1;
+            let mut profile = vec![f64::NEG_INFINITY; values];
+            let mut weight = vec![0.0; values];
+            for (bag, ll) in &points {
+                let idx = (bag[c] - lower[c]) as usize;
+                profile[idx] = profile[idx].max(*ll);
+                weight[idx] += (ll - best_ll).exp();
+            }
+            let inside = |idx: usize| profile[idx] >= best_ll - HALF_CHI2;
+            let confidence = (
+                lower[c] + (0..values).find(|&i| inside(i)).unwrap() as u32,
+                lower[c] + (0..values).rfind(|&i| inside(i)).unwrap() as u32,
+            );
+            let total = weight.iter().sum::<f64>();
+            let posterior_mean = weight.iter().enumerate().map(|(i, w)| (lower[c] + i as u32) as f64 * w).sum::<f64>() / total;
+            // Smallest and largest count with more than the tail's worth of
+            // weight beyond them.
+            let tail = (1.0 - LEVEL) / 2.0 * total;
+            let mut acc = 0.0;
+            let low = (0..values).find(|&i| {
+                acc += weight[i];
+                acc > tail
+            });
+            let mut acc = 0.0;
+            let high = (0..values).rev().find(|&i| {
+                acc += weight[i];
+                acc > tail
+            });
+            Estimate {
+                colour: colour.to_string(),
+                mle: best[c],
+                confidence,
+                posterior_mean,
+                credible: (lower[c] + low.unwrap_or(0) as u32, lower[c] + high.unwrap_or(0) as u32),
+            }
+        })
+        .collect();
+    Ok(estimates)
+}
+
+pub fn per_game(game: &Game, colours: &[&str], max: u32) -> Result<Vec<Estimate>, String> {
+    estimate(&game.picks.iter().collect::<Vec<_>>(), colours, max)
+}
+
+/// Every game played with the same bag.
+pub fn aggregate(games: &[Game], colours: &[&str], max: u32) -> Result<Vec<Estimate>, String> {
+    estimate(&games.iter().flat_map(|g| &g.picks).collect::<Vec<_>>(), colours, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::parse_games;
+
+    fn pick(text: &str) -> Pick {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn single_pick_is_its_own_bag() {
+        let p = pick("3 blue, 4 red");
+        let est = estimate(&[&p], &["blue", "red"], 12).unwrap();
+        assert_eq!((est[0].mle, est[1].mle), (3, 4));
+        // One handful says little about what else is in there.
+        assert_eq!(est[0].confidence.0, 3);
+        assert!(est[0].confidence.1 > 3);
+        assert!(est[0].posterior_mean > 3.0);
+    }
+
+    #[test]
+    fn symmetric_evidence() {
+        let (red, blue) = (pick("1 red"), pick("1 blue"));
+        let est = estimate(&[&red, &blue], &["red", "blue"], 3).unwrap();
+        // (1, 1), (2, 2) and (3, 3) are equally likely, the smallest wins.
+        assert_eq!((est[0].mle, est[1].mle), (1, 1));
+        assert!((est[0].posterior_mean - est[1].posterior_mean).abs() < 1e-9);
+        assert_eq!(est[0].credible, est[1].credible);
+    }
+
+    #[test]
+    fn never_drawn_colour_is_rare() {
+        let picks = (0..8).map(|_| pick("2 red")).collect::<Vec<_>>();
+        let est = estimate(&picks.iter().collect::<Vec<_>>(), &["red", "green"], 10).unwrap();
+        assert_eq!((est[1].mle, est[1].confidence.0), (0, 0));
+        assert!(est[1].posterior_mean < 1.0);
+        assert!(est[0].mle >= 2);
+    }
+
+    /// A fixed bag sampled over and over should be recovered roughly.
+    #[test]
+    fn recovers_sampled_bag() {
+        let bag = [("red", 3u32), ("blue", 9)];
+        let mut state = 7u64;
+        let mut picks = vec![];
+        for _ in 0..200 {
+            let mut cubes = bag.iter().flat_map(|(c, n)| std::iter::repeat_n(*c, *n as usize)).collect::<Vec<_>>();
+            let mut drawn = Pick::default();
+            for _ in 0..4 {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let colour = cubes.swap_remove((state >> 33) as usize % cubes.len());
+                drawn.add(colour, 1).unwrap();
+            }
+            picks.push(drawn);
+        }
+        let est = estimate(&picks.iter().collect::<Vec<_>>(), &["red", "blue"], 20).unwrap();
+        for (e, (_, truth)) in est.iter().zip(bag) {
+            assert!(e.confidence.0 <= truth && truth <= e.confidence.1, "{:?}", e);
+            assert!(e.credible.0 as f64 <= e.posterior_mean && e.posterior_mean <= e.credible.1 as f64, "{:?}", e);
+        }
+    }
+
+    #[test]
+    fn limits() {
+        let games = parse_games("Game 1: 20 red; 1 blue\n").unwrap();
+        assert!(per_game(&games[0], &["red", "blue"], 12).unwrap_err().contains("needs 20 red"));
+        let colours = ["a", "b", "c", "d", "e", "f"];
+        assert!(per_game(&games[0], &colours, 100).unwrap_err().contains("too many"));
+        assert!(aggregate(&games, &["red", "blue"], 25).unwrap()[0].mle >= 20);
+        assert!(per_game(&games[0], &colours, u32::MAX).unwrap_err().contains("too many"));
+        let games = parse_games("Game 1: 4000000000 red\n").unwrap();
+        assert!(per_game(&games[0], &["red"], 4000000000).unwrap_err().contains("too big"));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Scanner};
+use serde_json::{json, Value};
+
+/// Cube counts by colour, kept in the order the colours first showed up.
+/// Colours that aren't listed count as 0, so neither that nor the order
+/// matters when comparing.
+#[derive(Debug, Clone, Default)]
+pub struct Cubes(Vec<(String, u32)>);
+
+impl PartialEq for Cubes {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().all(|(colour, count)| other.get(colour) == count) && other.iter().all(|(colour, count)| self.get(colour) == count)
+    }
+}
+
+impl Eq for Cubes {}
+
+/// What one handful out of the bag showed.
+pub type Pick = Cubes;
+/// What the bag holds.
+pub type Bag = Cubes;
+
+impl Cubes {
+    /// A colour given twice keeps the later count.
+    pub fn new<S: Into<String>>(counts: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let mut ret = Cubes::default();
+        for (colour, count) in counts {
+            ret.set(&colour.into(), count);
+        }
+        ret
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.0.iter().find(|(c, _)| c == colour).map_or(0, |(_, n)| *n)
+    }
+
+    fn entry(&mut self, colour: &str) -> &mut u32 {
+        let idx = match self.0.iter().position(|(c, _)| c == colour) {
+            Some(idx) => idx,
+            None => {
+                self.0.push((colour.to_owned(), 0));
+                self.0.len() - 1
+            }
+        };
+        &mut self.0[idx].1
+    }
+
+    pub fn set(&mut self, colour: &str, count: u32) {
+        *self.entry(colour) = count;
+    }
+
+    /// The new count, or `None` and no change if it doesn't fit in a `u32`.
+    pub fn add(&mut self, colour: &str, count: u32) -> Option<u32> {
+        let entry = self.entry(colour);
+        *entry = entry.checked_add(count)?;
+        Some(*entry)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(c, n)| (c.as_str(), *n))
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(c, _)| c.as_str())
+    }
+
+    pub fn total(&self) -> u64 {
+        self.0.iter().map(|&(_, n)| n as u64).sum()
+    }
+
+    /// Whether a bag with `self` in it could also hold `other`.
+    pub fn contains(&self, other: &Cubes) -> bool {
+        other.iter().all(|(colour, count)| count <= self.get(colour))
+    }
+
+    /// Raises every count to at least what `other` has.
+    pub fn extend_to(&mut self, other: &Cubes) {
+        for (colour, count) in other.iter() {
+            let entry = self.entry(colour);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    /// Product of the counts of `colours`, missing ones count as 0.
+    pub fn power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> u64 {
+        colours.into_iter().map(|c| self.get(c) as u64).product()
+    }
+
+    // 4 green, 2 blue
+    pub fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        let mut ret = Cubes::default();
+        s.list(", ", |s| {
+            let at = *s;
+            let count = s.unsigned::<u32>()?;
+            s.tag(" ")?;
+            let colour = s.take_while1("colour", |c| c.is_alphabetic())?;
+            ret.add(colour, count).ok_or_else(|| at.error("smaller number"))?;
+            Ok(())
+        })?;
+        Ok(ret)
+    }
+
+    /// `{"green": 4, "blue": 2}`
+    pub fn to_json(&self) -> Value {
+        Value::Object(self.iter().map(|(colour, count)| (colour.to_owned(), json!(count))).collect())
+    }
+
+    /// Bag specification as given on the command line, `red=12,green=13`.
+    pub fn parse_spec(s: &mut Scanner) -> Result<Self, ParseError> {
+        let mut ret = Cubes::default();
+        s.list(",", |s| {
+            s.spaces();
+            let colour = s.take_while1("colour", |c| c.is_alphabetic())?;
+            s.tag("=")?;
+            ret.set(colour, s.unsigned()?);
+            s.spaces();
+            Ok(())
+        })?;
+        Ok(ret)
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    /// Either form, `4 green, 2 blue` or `green=4,blue=2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(s);
+        let ret = if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            Cubes::parse(&mut s)?
+        } else {
+            Cubes::parse_spec(&mut s)?
+        };
+        s.end()?;
+        Ok(ret)
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (colour, count)) in self.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}", colour, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub id: u32,
+    pub picks: Vec<Pick>,
+}
+
+impl Game {
+    /// The fewest cubes of each colour this game could have been played with.
+    pub fn min_bag(&self) -> Bag {
+        let mut ret = Bag::default();
+        for pick in &self.picks {
+            ret.extend_to(pick);
+        }
+        ret
+    }
+
+    pub fn possible_with(&self, bag: &Bag) -> bool {
+        self.picks.iter().all(|pick| bag.contains(pick))
+    }
+}
+
+impl FromStr for Game {
+    // Game 1: 4 green, 2 blue; 1 red, 1 blue, 4 green; 3 green, 4 blue, 1 red; 7 green, 2 blue, 4 red; 3 red, 7 green; 3 red, 3 green
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(s);
+        s.tag("Game ")?;
+        let id = s.unsigned()?;
+        s.tag(": ")?;
+        let picks = s.list("; ", Pick::parse)?;
+        s.end()?;
+        Ok(Game { id, picks })
+    }
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(|line| Game::from_str(line).map_err(|e| e.locate(input))).collect()
+}
+
+/// Every colour seen in any game, in order of first appearance.
+pub fn colours(games: &[Game]) -> Vec<&str> {
+    let mut ret: Vec<&str> = vec![];
+    for colour in games.iter().flat_map(|g| &g.picks).flat_map(|p| p.colours()) {
+        if !ret.contains(&colour) {
+            ret.push(colour);
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick() {
+        let pick = Pick::from_str("4 green, 2 blue").unwrap();
+        assert_eq!(pick.get("red"), 0);
+        assert_eq!(pick.get("green"), 4);
+        assert_eq!(pick.get("blue"), 2);
+        let pick = Pick::from_str("1 purple, 3 red, 2 purple").unwrap();
+        assert_eq!(pick.get("purple"), 3);
+        assert_eq!(pick.to_string(), "purple=3,red=3");
+        assert_eq!(Pick::from_str("1 red, 2 blue"), Pick::from_str("2 blue, 1 red, 0 green"));
+        assert_ne!(Pick::from_str("1 red, 2 blue"), Pick::from_str("2 blue"));
+        let err = Pick::from_str("4000000000 red, 4000000000 red").unwrap_err();
+        assert_eq!((err.expected.as_str(), err.found.as_str()), ("smaller number", "4000000000"));
+        let bag = Bag::from_str("red=4000000000,blue=4000000000").unwrap();
+        assert_eq!(bag.total(), 8_000_000_000);
+    }
+
+    #[test]
+    fn test_game() {
+        let game = Game::from_str("Game 1: 4 green, 2 blue; 1 red, 1 blue, 4 green; 3 green, 4 blue, 1 red; 7 green, 2 blue, 4 red; 3 red, 7 green; 3 red, 3 green").unwrap();
+        assert_eq!(game.id, 1);
+        assert_eq!(game.picks.len(), 6);
+        let expected = [(0, 4, 2), (1, 4, 1), (1, 3, 4), (4, 7, 2), (3, 7, 0), (3, 3, 0)];
+        for (pick, (red, green, blue)) in game.picks.iter().zip(expected) {
+            assert_eq!((pick.get("red"), pick.get("green"), pick.get("blue")), (red, green, blue));
+        }
+        assert_eq!(game.min_bag().to_string(), "green=7,blue=4,red=4");
+        assert_eq!(game.min_bag().power(["red", "green", "blue"]), 112);
+        assert_eq!(game.min_bag().power(["red", "purple"]), 0);
+    }
+
+    #[test]
+    fn test_bag_spec() {
+        let bag = Bag::from_str("red=12, green=13,blue=14,purple=3").unwrap();
+        assert_eq!(bag.to_string(), "red=12,green=13,blue=14,purple=3");
+        assert!(bag.contains(&Pick::from_str("3 purple, 12 red").unwrap()));
+        assert!(!bag.contains(&Pick::from_str("1 orange").unwrap()));
+        let spec = "red=12,green";
+        let err = Bag::from_str(spec).unwrap_err().locate(spec);
+        assert_eq!((err.column, err.expected.as_str()), (13, "'='"));
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod estimate;
+pub mod game;
+pub mod part1;
+pub mod part2;
+pub mod query;
+pub mod report;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn solve_part1(input: &str) -> Result<u32, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<u64, ParseError> {
+        part2::solve(input)
+    }
+}
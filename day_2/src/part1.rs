@@ -0,0 +1,30 @@
+use aoc_common::ParseError;
+
+use crate::game::parse_games;
+use crate::query::{default_bag, possible};
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let games = parse_games(input)?;
+    Ok(possible(&games, &default_bag()).iter().map(|game| game.id).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_input() {
+        // Any colour goes, but it has to be a word.
+        assert!(solve("Game 1: 4 green, 2 blue\nGame 2: 3 red, 1 purple\n").is_ok());
+        let input = "Game 1: 4 green, 2 blue\nGame 2: 3 red, 1 7\n";
+        let err = solve(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 18));
+        assert_eq!(err.found, "7");
+
+        let err = solve("Game x: 1 red").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let err = solve("Game 1: 1 red,2 blue").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (14, ",2"));
+    }
+}
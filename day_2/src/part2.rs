@@ -0,0 +1,9 @@
+use aoc_common::ParseError;
+
+use crate::game::parse_games;
+use crate::query::min_bags;
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    let games = parse_games(input)?;
+    Ok(min_bags(&games).iter().map(|(_, _, power)| *power).sum())
+}
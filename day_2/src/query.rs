@@ -0,0 +1,165 @@
+//! Questions about a set of games, answered for any colours.
+
+use crate::game::{colours, Bag, Game};
+
+/// The bag from the puzzle.
+pub fn default_bag() -> Bag {
+    Bag::new([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+pub fn possible<'a>(games: &'a [Game], bag: &Bag) -> Vec<&'a Game> {
+    games.iter().filter(|g| g.possible_with(bag)).collect()
+}
+
+/// Minimum bag of every game and its power. The power multiplies the counts
+/// of every colour in the input, so a game that never shows one of them has
+/// power 0.
+pub fn min_bags(games: &[Game]) -> Vec<(u32, Bag, u64)> {
+    let colours = colours(games);
+    games
+        .iter()
+        .map(|g| {
+            let bag = g.min_bag();
+            let power = bag.power(colours.iter().copied());
+            (g.id, bag, power)
+        })
+        .collect()
+}
+
+/// The bag with the fewest cubes in total that makes at least `percent` of
+/// the games possible, along with how many it makes possible.
+///
+/// Thresholds for all colours but the last are tried from the values the
+/// games actually need, the last colour then follows from the games that are
+/// left. Branches that can't beat the best bag so far are cut.
+pub fn smallest_bag(games: &[Game], percent: f64) -> (Bag, usize) {
+    let needed = ((games.len() as f64 * percent / 100.0).ceil() as usize).min(games.len());
+    let colours = colours(games);
+    let bags = games
+        .iter()
+        .map(|g| {
+            let bag = g.min_bag();
+            colours.iter().map(|c| bag.get(c)).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut best = None;
+    search(&bags.iter().collect::<Vec<_>>(), needed, &mut vec![], &mut best);
+    // Colours left out once enough games fit need no cubes at all.
+    let mut counts = best.map(|(_, counts)| counts).unwrap_or_default();
+    counts.resize(colours.len(), 0);
+    let bag = Bag::new(colours.iter().copied().zip(counts));
+    let fits = possible(games, &bag).len();
+    (bag, fits)
+}
+
+/// `bags` are the minimum bags still fitting under the thresholds picked so
+/// far in `chosen`, `best` the smallest total found and its thresholds.
+fn search(bags: &[&Vec<u32>], needed: usize, chosen: &mut Vec<u32>, best: &mut Option<(u64, Vec<u32>)>) {
+    let so_far: u64 = chosen.iter().map(|&n| n as u64).sum();
+    if bags.len() < needed || best.as_ref().is_some_and(|(total, _)| so_far >= *total) {
+        return;
+    }
+    let colour = chosen.len();
+    let colours = bags.first().map_or(0, |b| b.len());
+    if needed == 0 || colour == colours {
+        if best.as_ref().is_none_or(|(total, _)| so_far < *total) {
+            *best = Some((so_far, chosen.clone()));
+        }
+        return;
+    }
+    let mut values = bags.iter().map(|b| b[colour]).collect::<Vec<_>>();
+    values.sort_unstable();
+    if colour == colours - 1 {
+        // The last colour just has to cover the `needed` cheapest games.
+        chosen.push(values[needed - 1]);
+        search(&[], 0, chosen, best);
+        chosen.pop();
+        return;
+    }
+    values.dedup();
+    for value in values {
+        let fitting = bags.iter().copied().filter(|b| b[colour] <= value).collect::<Vec<_>>();
+        chosen.push(value);
+        search(&fitting, needed, chosen, best);
+        chosen.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::parse_games;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let ids = possible(&games, &default_bag()).iter().map(|g| g.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 2, 5]);
+        let powers = min_bags(&games).iter().map(|(_, _, p)| *p).collect::<Vec<_>>();
+        assert_eq!(powers, vec![48, 12, 1560, 630, 36]);
+    }
+
+    #[test]
+    fn other_colours() {
+        let games = parse_games("Game 1: 2 purple, 1 red\nGame 2: 5 red\nGame 3: 1 purple\n").unwrap();
+        let bag = "red=5,purple=1".parse().unwrap();
+        let ids = possible(&games, &bag).iter().map(|g| g.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![2, 3]);
+        let bags = min_bags(&games);
+        assert_eq!(bags[0].1.to_string(), "purple=2,red=1");
+        assert_eq!(bags.iter().map(|(_, _, p)| *p).collect::<Vec<_>>(), vec![2, 0, 0]);
+    }
+
+    #[test]
+    fn smallest() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let (bag, fits) = smallest_bag(&games, 100.0);
+        assert_eq!(bag.to_string(), "blue=15,red=20,green=13");
+        assert_eq!(fits, 5);
+        let (bag, fits) = smallest_bag(&games, 60.0);
+        // Games 1, 2 and 5: 6 blue, 6 red, 3 green.
+        assert_eq!((bag.total(), fits), (15, 3));
+        let (bag, fits) = smallest_bag(&games, 0.0);
+        assert_eq!((bag.total(), fits), (0, 0));
+    }
+
+    /// Tries every combination of thresholds, for checking `smallest_bag`.
+    fn brute_force(games: &[Game], needed: usize) -> u64 {
+        let colours = colours(games);
+        let max = games.iter().map(|g| g.min_bag().iter().map(|(_, n)| n).max().unwrap_or(0)).max().unwrap_or(0);
+        let mut best = u64::MAX;
+        let mut counts = vec![0; colours.len()];
+        loop {
+            let bag = Bag::new(colours.iter().copied().zip(counts.iter().copied()));
+            if possible(games, &bag).len() >= needed {
+                best = best.min(bag.total());
+            }
+            let Some(idx) = counts.iter().position(|&c| c < max) else {
+                return best;
+            };
+            counts[..idx].iter_mut().for_each(|c| *c = 0);
+            counts[idx] += 1;
+        }
+    }
+
+    #[test]
+    fn smallest_matches_brute_force() {
+        let games = parse_games(
+            "Game 1: 3 a, 1 b; 2 c\nGame 2: 1 a, 4 c\nGame 3: 2 b, 2 d\nGame 4: 4 a, 1 d\nGame 5: 1 a, 1 b, 1 c, 1 d\nGame 6: 3 c, 3 b\n",
+        )
+        .unwrap();
+        for percent in [0.0, 10.0, 34.0, 50.0, 66.7, 83.0, 100.0] {
+            let (bag, fits) = smallest_bag(&games, percent);
+            let needed = (games.len() as f64 * percent / 100.0).ceil() as usize;
+            assert!(fits >= needed);
+            assert_eq!(bag.total(), brute_force(&games, needed), "{}%", percent);
+        }
+    }
+}
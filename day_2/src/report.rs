@@ -0,0 +1,164 @@
+//! Per-colour statistics over a game log, for exporting as CSV or JSON.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_json::{json, Map, Value};
+
+use crate::game::{colours, Bag, Game};
+use crate::query::min_bags;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColourStats {
+    pub colour: String,
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    /// How often each count came up.
+    pub histogram: BTreeMap<u32, usize>,
+}
+
+impl ColourStats {
+    fn new(colour: &str, counts: impl IntoIterator<Item = u32>) -> Self {
+        let mut histogram = BTreeMap::new();
+        for count in counts {
+            *histogram.entry(count).or_insert(0) += 1;
+        }
+        let n = histogram.values().sum::<usize>();
+        let sum = histogram.iter().map(|(count, times)| *count as u64 * *times as u64).sum::<u64>();
+        ColourStats {
+            colour: colour.to_owned(),
+            min: histogram.keys().next().copied().unwrap_or(0),
+            max: histogram.keys().next_back().copied().unwrap_or(0),
+            mean: if n == 0 { 0.0 } else { sum as f64 / n as f64 },
+            histogram,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let histogram = self.histogram.iter().map(|(k, v)| (k.to_string(), json!(v))).collect::<Map<_, _>>();
+        json!({ "min": self.min, "max": self.max, "mean": self.mean, "histogram": histogram })
+    }
+
+    /// `0:12 1:30 4:2`, no commas so it fits in a CSV field.
+    fn histogram_text(&self) -> String {
+        self.histogram.iter().map(|(k, v)| format!("{}:{}", k, v)).collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub colours: Vec<String>,
+    /// Counts in single picks, a colour missing from a pick counts as 0.
+    pub per_pick: Vec<ColourStats>,
+    /// Most of each colour any pick of a game showed.
+    pub per_game: Vec<ColourStats>,
+    /// Game ID, minimum bag and its power.
+    pub min_bags: Vec<(u32, Bag, u64)>,
+}
+
+impl Report {
+    pub fn new(games: &[Game]) -> Self {
+        let colours = colours(games);
+        let min_bags = min_bags(games);
+        let per_pick = colours
+            .iter()
+            .map(|c| ColourStats::new(c, games.iter().flat_map(|g| &g.picks).map(|p| p.get(c))))
+            .collect();
+        let per_game = colours.iter().map(|c| ColourStats::new(c, min_bags.iter().map(|(_, bag, _)| bag.get(c)))).collect();
+        Report { colours: colours.iter().map(|c| c.to_string()).collect(), per_pick, per_game, min_bags }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let stats = |all: &[ColourStats]| all.iter().map(|s| (s.colour.clone(), s.to_json())).collect::<Map<_, _>>();
+        let games = self
+            .min_bags
+            .iter()
+            .map(|(id, bag, power)| json!({ "id": id, "min_bag": bag.to_json(), "power": power }))
+            .collect::<Vec<_>>();
+        json!({
+            "colours": self.colours,
+            "per_pick": stats(&self.per_pick),
+            "per_game": stats(&self.per_game),
+            "games": games,
+        })
+    }
+
+    /// One line per scope and colour.
+    pub fn colours_csv(&self) -> String {
+        let mut ret = String::from("scope,colour,min,max,mean,histogram\n");
+        for (scope, all) in [("pick", &self.per_pick), ("game", &self.per_game)] {
+            for s in all {
+                writeln!(ret, "{},{},{},{},{:.3},{}", scope, s.colour, s.min, s.max, s.mean, s.histogram_text()).unwrap();
+            }
+        }
+        ret
+    }
+
+    /// One line per game with its minimum bag and power.
+    pub fn games_csv(&self) -> String {
+        let mut ret = format!("id,{},power\n", self.colours.join(","));
+        for (id, bag, power) in &self.min_bags {
+            let counts = self.colours.iter().map(|c| bag.get(c).to_string()).collect::<Vec<_>>();
+            writeln!(ret, "{},{},{}", id, counts.join(","), power).unwrap();
+        }
+        ret
+    }
+
+    pub fn text(&self) -> String {
+        let mut ret = String::new();
+        writeln!(ret, "{:<6} {:<10} {:>4} {:>4} {:>7}  histogram", "scope", "colour", "min", "max", "mean").unwrap();
+        for (scope, all) in [("pick", &self.per_pick), ("game", &self.per_game)] {
+            for s in all {
+                writeln!(ret, "{:<6} {:<10} {:>4} {:>4} {:>7.3}  {}", scope, s.colour, s.min, s.max, s.mean, s.histogram_text())
+                    .unwrap();
+            }
+        }
+        let total = self.min_bags.iter().map(|(_, _, p)| p).sum::<u64>();
+        writeln!(ret, "{} games, sum of minimum bag powers {}", self.min_bags.len(), total).unwrap();
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{parse_games, Cubes};
+
+    fn report() -> Report {
+        Report::new(&parse_games("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue, 2 green; 3 green, 4 blue, 1 red\n").unwrap())
+    }
+
+    #[test]
+    fn stats() {
+        let report = report();
+        assert_eq!(report.colours, vec!["blue", "red", "green"]);
+        let blue = &report.per_pick[0];
+        assert_eq!((blue.min, blue.max), (0, 6));
+        assert!((blue.mean - 14.0 / 5.0).abs() < 1e-9);
+        assert_eq!(blue.histogram, BTreeMap::from([(0, 1), (1, 1), (3, 1), (4, 1), (6, 1)]));
+        let red = &report.per_game[1];
+        assert_eq!((red.min, red.max, red.mean), (1, 4, 2.5));
+    }
+
+    #[test]
+    fn csv() {
+        let report = report();
+        let colours = report.colours_csv();
+        let lines = colours.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[1], "pick,blue,0,6,2.800,0:1 1:1 3:1 4:1 6:1");
+        assert_eq!(lines[4], "game,blue,4,6,5.000,4:1 6:1");
+        assert_eq!(report.games_csv(), "id,blue,red,green,power\n1,6,4,2,48\n2,4,1,3,12\n");
+    }
+
+    #[test]
+    fn json() {
+        let json = report().to_json();
+        assert_eq!(json["colours"], json!(["blue", "red", "green"]));
+        assert_eq!(json["per_pick"]["green"]["histogram"], json!({ "0": 1, "2": 3, "3": 1 }));
+        assert_eq!(json["games"][1], json!({ "id": 2, "min_bag": { "blue": 4, "green": 3, "red": 1 }, "power": 12 }));
+        let pick: Cubes = "4 green, 2 blue".parse().unwrap();
+        assert_eq!(pick.to_json(), json!({ "green": 4, "blue": 2 }));
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+use grid::{Grid, Point};
+use tracing::debug;
+
+struct Map {
+    data: Grid<char>,
+    start: Point,
+}
+
+impl Map {
+    fn get_neighbors(&self, coord: Point) -> Vec<Point> {
+        self.data
+            .neighbors4(coord)
+            .filter(|&p| self.data[p] != '#')
+            .collect()
+    }
+
+    fn find_num_reachable_tiles(&self, start: Point, steps: usize) -> usize {
+        // (coord, steps)
+        let mut the_queue: VecDeque<(Point, usize)> = VecDeque::new();        
+
+        the_queue.push_back((start, 0));
+
+        while !the_queue.iter().all(|e| e.1 == steps) {
+            let tile = the_queue.pop_front().unwrap();
+
+            for neighbor in self.get_neighbors(tile.0) {
+                let data = (neighbor, tile.1 + 1);
+                if !the_queue.contains(&data) {
+                    the_queue.push_back(data);
+                }
+            }
+        }
+
+        the_queue.len()
+    }
+
+    fn find_start(&self) -> Point {
+        self.start
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let data = Grid::try_parse(value, |ch| matches!(ch, '.' | '#' | 'S').then_some(ch))?;
+        let start = data
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::end_of_input("start tile 'S'", value))?;
+        Ok(Map {
+            data,
+            start
+        })
+    }
+}
+
+pub fn solve(content: &str) -> Result<usize, ParseError> {
+    let m: Map = content.parse()?;
+
+    debug!("Start is: {:?}", m.find_start());
+    Ok(m.find_num_reachable_tiles(m.find_start(), 64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_maps() {
+        assert_eq!(solve("...\n.#.\n").unwrap_err().to_string(), "line 3, column 1: expected start tile 'S', found end of input");
+        assert_eq!(solve(".S.\n.x.\n").unwrap_err().found, "x");
+        assert_eq!("".parse::<Map>().err().unwrap().expected, "at least one row");
+        assert_eq!(solve("#S#\n").unwrap(), 0);
+    }
+}
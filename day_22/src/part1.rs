@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::fmt::Write;
+use std::str::FromStr;
+use aoc_common::{ParseError, Scanner};
+use tracing::{trace, Level};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Vec2d(i32,i32,i32);
+
+impl Vec2d {
+    // 1,0,1
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        let x = s.unsigned()?;
+        s.tag(",")?;
+        let y = s.unsigned()?;
+        s.tag(",")?;
+        let z = s.unsigned()?;
+        Ok(Self(x, y, z))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Extents {
+    min: Vec2d,
+    max: Vec2d
+}
+
+impl Extents {
+    fn intersects(&self, rhs: &Extents) -> bool {
+        let no_intersect = self.min.0 < rhs.min.0 && self.max.0 < rhs.min.0 ||
+            self.min.0 > rhs.max.0 && self.max.0 > rhs.max.0 ||
+            self.min.1 < rhs.min.1 && self.max.1 < rhs.min.1 ||
+            self.min.1 > rhs.max.1 && self.max.1 > rhs.max.1 ||
+            self.min.2 < rhs.min.2 && self.max.2 < rhs.min.2 ||
+            self.min.2 > rhs.max.2 && self.max.2 > rhs.max.2;
+        !no_intersect
+    }
+    
+    #[allow(dead_code)]
+    fn contains(&self, point: Vec2d) -> bool {
+        point.0 >= self.min.0 && point.0 <= self.max.0 &&
+        point.1 >= self.min.1 && point.1 <= self.max.1 &&
+        point.2 >= self.min.2 && point.2 <= self.max.2
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Brick {
+    name: Option<char>,
+    extents: Extents,
+    rests_on: Vec<usize>
+}
+impl FromStr for Extents {
+    type Err = ParseError;
+
+    // 1,0,1~1,2,1
+    fn from_str(value: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(value);
+        let min = Vec2d::parse(&mut s)?;
+        s.tag("~")?;
+        let max = Vec2d::parse(&mut s)?;
+        s.end()?;
+        Ok(Self {
+            min,
+            max
+        })
+    }
+}
+
+impl Brick {
+    fn intersects(&self, rhs: &Brick) -> bool {
+        self.extents.intersects(&rhs.extents)
+    }
+
+    fn lower(&self) -> Brick {
+        let mut ret = self.clone();
+        ret.extents.min.2 -= 1;
+        ret.extents.max.2 -= 1;
+        ret
+    }
+}
+
+impl FromStr for Brick {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            name: None,
+            extents: value.parse()?,
+            rests_on: vec![]
+        })
+    }
+}
+
+enum ViewDirection {
+    Front,
+    Side
+}
+
+fn print_bricks(map: &BTreeMap<usize, RefCell<Vec<usize>>>, bricks: &[RefCell<Brick>], view: ViewDirection) {
+    if !tracing::enabled!(Level::TRACE) {
+        return;
+    }
+    let mut out = String::new();
+    let max_z = *map.last_key_value().unwrap().0;
+    match view {
+        ViewDirection::Front => {
+            writeln!(out, " x ").unwrap();
+        },
+        ViewDirection::Side => {
+            writeln!(out, " y ").unwrap();
+        }
+    }
+    writeln!(out, "012").unwrap();
+    for z in (1usize..=max_z).rev() {
+        if !map.contains_key(&z) {
+            writeln!(out, "... {}", z).unwrap();
+            continue;
+        }
+        let mut z_container = map[&z].borrow().clone();
+        match view {
+            ViewDirection::Front => {
+                z_container.sort_by_key(|&b| bricks[b].borrow().extents.min.1);
+            },
+            ViewDirection::Side => {
+                z_container.sort_by_key(|&b| bricks[b].borrow().extents.min.0);
+            }
+
+        }
+        for i in 0..3i32 {
+            match view {
+                ViewDirection::Front => {
+                    let brick_count = z_container
+                        .iter()
+                        .filter(|&&brick| i >= bricks[brick].borrow().extents.min.0 && i <= bricks[brick].borrow().extents.max.0)
+                        .count();
+                    if brick_count > 1 {
+                        write!(out, "?").unwrap();
+                    } else if brick_count == 0 {
+                        write!(out, ".").unwrap();
+                    } else {
+                        let brick_idx = z_container.iter().find(|&&brick| i >= bricks[brick].borrow().extents.min.0 && i <= bricks[brick].borrow().extents.max.0).unwrap();
+                        let brick = bricks[*brick_idx].borrow();
+                        if i >= brick.extents.min.0 && i <= brick.extents.max.0 {
+                            write!(out, "{}", brick.name.unwrap()).unwrap();
+                        }
+                    }
+                },
+                ViewDirection::Side => {
+                    let brick_count = z_container
+                        .iter()
+                        .filter(|&&brick| i >= bricks[brick].borrow().extents.min.1 && i <= bricks[brick].borrow().extents.max.1)
+                        .count();
+                    if brick_count > 1 {
+                        write!(out, "?").unwrap();
+                    } else if brick_count == 0 {
+                        write!(out, ".").unwrap();
+                    } else {
+                        let brick_idx = z_container.iter().find(|&&brick| i >= bricks[brick].borrow().extents.min.1 && i <= bricks[brick].borrow().extents.max.1).unwrap();
+                        let brick = bricks[*brick_idx].borrow();
+                        if i >= brick.extents.min.1 && i <= brick.extents.max.1 {
+                            write!(out, "{}", brick.name.unwrap()).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+        writeln!(out, " {}", z).unwrap();
+    }
+    trace!("\n{}", out);
+}
+
+fn drop_pieces(map: &mut BTreeMap<usize, RefCell<Vec<usize>>>, bricks: &mut [RefCell<Brick>]) {
+    for (idx,brick) in bricks.iter().enumerate() {
+        loop {
+            if brick.borrow().extents.min.2 < 2 {
+                break;
+            }
+            let new_level = brick.borrow().extents.min.2 as usize-1usize;
+            trace!("{}", new_level);
+            map.entry(new_level).or_insert_with(|| RefCell::new(vec![]));
+            let mut dst = map[&new_level].borrow_mut();
+            let mut intersect = false;
+            for &b2 in dst.iter() {
+                if bricks[b2].borrow().intersects(&brick.borrow().lower()) {
+                    brick.borrow_mut().rests_on.push(b2);
+                    intersect = true;
+                    //break;
+                }
+            }
+            if !intersect {
+                // Remove from old maximum level
+                let map_entry = &map[&(brick.borrow().extents.max.2 as usize)];
+                let pos = map_entry.borrow().iter().position(|&b| b == idx).unwrap();
+                map_entry.borrow_mut().remove(pos);
+
+                // Add to new minimum level
+                dst.push(idx);
+
+                let lowered = brick.borrow().lower();
+                *brick.borrow_mut() = lowered;
+            } else {
+                break;
+            }
+        }
+         
+    }
+}
+
+
+pub fn solve(content: &str) -> Result<usize, ParseError> {
+    let mut next_name = 'A';
+
+    let mut max_z = 0;
+    let mut bricks = vec![];
+    for l in content.lines() {
+        let mut ret = l.parse::<Brick>().map_err(|e| e.locate(content))?;
+        max_z = std::cmp::max(ret.extents.max.2, max_z);
+        ret.name = Some(next_name);
+        next_name = char::from_u32(u32::from(next_name) + 1).unwrap_or(next_name);
+        bricks.push(RefCell::new(ret));
+    }
+    if bricks.is_empty() {
+        return Err(ParseError::end_of_input("a brick", content));
+    }
+
+    bricks.sort_by_key(|b| b.borrow().extents.min.2);
+
+    let mut brick_levels: BTreeMap<usize, RefCell<Vec<usize>>> = BTreeMap::new();
+    for (idx,brick) in bricks.iter().enumerate() {
+        for i in brick.borrow().extents.min.2 as usize..=brick.borrow().extents.max.2 as usize {
+            brick_levels.entry(i)
+                .and_modify(|v| { 
+                    v.deref().borrow_mut().push(idx);
+                })
+                .or_insert(RefCell::new(vec![idx]));
+        }
+    }
+
+    for b in &bricks {
+        trace!("{:?}", b);
+    }
+    trace!("{:?}", brick_levels);
+
+    drop_pieces(&mut brick_levels, &mut bricks);
+
+    print_bricks(&brick_levels, &bricks, ViewDirection::Front);
+    print_bricks(&brick_levels, &bricks, ViewDirection::Side);
+
+    /*
+    println!();
+    for b in &bricks {
+        print!("{} rests on ", b.borrow().name.unwrap());
+        for support in &b.borrow().rests_on {
+            print!("{} ", bricks[*support].borrow().name.unwrap());
+        }
+        println!();
+    }
+    */
+
+    Ok(bricks
+        .iter()
+        .enumerate()
+        .fold(0, |acc,(idx,_)| {
+            let where_am_i_cnt = bricks.iter().filter(|&this_brick| this_brick.borrow().rests_on.contains(&idx)).count();
+            let never_only_support  = bricks
+                .iter()
+                .filter(|&this_brick| this_brick.borrow().rests_on.contains(&idx))
+                .all(|this_brick| this_brick.borrow().rests_on.len() > 1);
+            acc + ((where_am_i_cnt == 0 || never_only_support) as usize)
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_bricks() {
+        assert!("1,0,1~1,2,1".parse::<Brick>().is_ok());
+        assert_eq!("1,0,1-1,2,1".parse::<Brick>().unwrap_err().expected, "'~'");
+        assert_eq!("1,0,-1~1,2,1".parse::<Brick>().unwrap_err().found, "-1~1,2,1");
+        let err = solve("1,0,1~1,2,1\nhello world\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
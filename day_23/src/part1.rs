@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use core::fmt::Formatter;
+
+use aoc_common::ParseError;
+use grid::{Grid, Point, Vector};
+use tracing::trace;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+struct Node {
+    coord: Point,
+    // for debugging
+    prev: Option<Point>
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.coord == other.coord && self.prev == other.prev
+    }
+}
+impl Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coord.hash(state);
+    }
+}
+
+impl Eq for Node {}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Node({})", self.coord)
+    }
+}
+
+type Map = Grid<char>;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct Edge(Point, usize);
+
+const OFFSETS: [Vector; 4] = Vector::ORTHOGONAL;
+
+fn get_neighbors(my_node: &Point, prev: Option<&Point>, map: &Map) -> Vec<Point> {
+    let mut ret = vec![];
+
+    let offsets = match map[*my_node] {
+        '>' => &[ Vector(1, 0) ],
+        '<' => &[ Vector(-1, 0) ],
+        '^' => &[ Vector(0, -1) ],
+        'v' => &[ Vector(0, 1) ],
+        '.' => &OFFSETS[..],
+        _ => &[]
+    };
+
+    for offset in offsets {
+        let Some(next_point) = map.step(*my_node, *offset) else {
+            continue;
+        };
+        if map[next_point] == '#' || prev.is_some() && *prev.unwrap() == next_point {
+            continue;
+        }
+        let dir = next_point - *my_node;
+        let legal_travel = match map[next_point] {
+            '>' if dir== Vector(1, 0) => true,
+            '<' if dir== Vector(-1, 0) => true,
+            '^' if dir== Vector(0, -1) => true,
+            'v' if dir== Vector(0, 1) => true,
+            '.' => true,
+            _ => false
+        };
+        if legal_travel {
+            ret.push(next_point);
+        }
+    }
+    ret
+}
+
+fn read_graph(map: &Map, start: Point) -> HashMap<Point, HashSet<Edge>> {
+    let mut ret = HashMap::new();
+
+    // cur point, from node, edge length, previous point
+    let mut the_stack: Vec<(Point, Point, usize, Option<Point>)> = vec![ (start, start, 0, None) ];
+    while let Some((cur, from, edge_len, prev)) = the_stack.pop() {
+
+        let neighs = get_neighbors(&cur, prev.as_ref(), map);
+        if neighs.len() == 1 {
+            the_stack.push((*neighs.first().unwrap(), from, edge_len + 1, Some(cur)));
+        } else {
+            let mut set2 = HashSet::new();
+            set2.insert(Edge(from, edge_len));
+            ret.entry(cur).and_modify(|v: &mut HashSet<Edge>| { 
+                // let mut to_rem = None;
+                // if let Some(prev_entry) = v.iter().find(|&e| e.0 == from) {
+                //     if edge_len > prev_entry.1 {
+                //         to_rem = Some(*prev_entry);
+                //     }
+                // }
+                // let old_len = v.len();
+                // if let Some(to_rem) = to_rem {
+                //     assert!(v.remove(&to_rem));
+                //     assert!(v.len() == old_len-1);
+                // }
+                v.insert(Edge(from, edge_len)); 
+            })
+                .or_insert(set2);
+            for neigh in &neighs {
+                the_stack.push((*neigh, cur, 1, Some(cur)));
+            }
+        }
+    }
+    ret
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    path: Vec<Point>,
+    len: usize
+}
+
+fn find_path_len(start: &Point, dest: &Point, graph: &HashMap<Point, HashSet<Edge>>) -> Option<usize> {
+    let mut the_stack = vec![ Path { path: vec![*dest], len: 0 }];
+    let mut memory: HashMap<Point, Path> = HashMap::new();
+    while let Some(cur) = the_stack.pop() {
+        let head = *cur.path.last().unwrap();
+        // Start node won't have an entry
+        if !graph.contains_key(&head) {
+            continue;
+        }
+        let entry = &graph[&head];
+        trace!("Now at {:?}", cur);
+
+        for edge in entry {
+            // if cur.path.contains(&edge.0) {
+            //     continue;
+            // }
+            let mut tmp = cur.clone();
+            tmp.path.push(edge.0);
+            tmp.len += edge.1;
+            if !memory.contains_key(&edge.0) || tmp.len > memory.get(&edge.0).unwrap().len {
+                if memory.contains_key(&edge.0) {
+                    trace!("Replacing {:?} with {:?}", memory.get(&edge.0).unwrap(), tmp);
+                }
+                memory.insert(edge.0, tmp.clone());
+                the_stack.push(tmp);
+            }
+        }
+        trace!("-------------------");
+    }
+    memory.get(start).map(|path| path.len)
+}
+
+/// The gap in the top row, i.e. where the trail starts, or the one in the
+/// bottom row where it ends.
+fn find_gap(map: &Map, y: usize) -> Result<Point, ParseError> {
+    map.row(y)
+        .iter()
+        .position(|&c| c == '.')
+        .map(|x| Point(x, y))
+        .ok_or_else(|| ParseError::at(y + 1, 1, "a '.' in this row", map.row(y).iter().collect::<String>()))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let map: Map = Grid::try_parse(input, |ch| matches!(ch, '.' | '#' | '>' | '<' | '^' | 'v').then_some(ch))?;
+    let start = find_gap(&map, 0)?;
+    let dest = find_gap(&map, map.height() - 1)?;
+
+    let graph = read_graph(&map, start);
+    //assert!(graph[&Point(3,5)].len() == 3);
+    for (node, edges) in &graph {
+        trace!("{} -> {}", node, edges.iter().map(|edge| format!("({}, {})", edge.0, edge.1)).collect::<Vec<_>>().join(", "));
+    }
+
+    find_path_len(&start, &dest, &graph)
+        .ok_or_else(|| ParseError::at(dest.1 + 1, dest.0 + 1, "a trail from the start to here", "none"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_maps() {
+        assert_eq!(solve("#.#\n#x#\n").unwrap_err().found, "x");
+        assert_eq!(solve("###\n#.#\n").unwrap_err().to_string(), "line 1, column 1: expected a '.' in this row, found '###'");
+        assert_eq!(solve("#.#\n###\n").unwrap_err().line, 2);
+        assert_eq!(solve("#.#\n#.#\n#.#\n"), Ok(2));
+    }
+}
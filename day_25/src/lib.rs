@@ -0,0 +1,18 @@
+use aoc_common::{ParseError, Solution, Unsolved};
+
+pub mod part1;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn solve_part1(input: &str) -> Result<usize, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(_input: &str) -> Result<Unsolved, ParseError> {
+        Ok(Unsolved)
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+
+use day_3::schematic::Schematic;
+
+fn main() -> ExitCode {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let schematic = match Schematic::parse(&input) {
+        Ok(schematic) => schematic,
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(path));
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = day_3::tui::run(&schematic) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
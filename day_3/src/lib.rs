@@ -0,0 +1,22 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod query;
+pub mod schematic;
+pub mod tui;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn solve_part1(input: &str) -> Result<i128, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<i128, ParseError> {
+        part2::solve(input)
+    }
+}
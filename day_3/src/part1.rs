@@ -0,0 +1,30 @@
+use aoc_common::ParseError;
+use tracing::{debug, trace};
+
+use crate::query;
+use crate::schematic::Schematic;
+
+pub fn solve(data: &str) -> Result<i128, ParseError> {
+    let schem = Schematic::parse(data)?;
+    let rel_numbers = query::part_numbers().run(&schem);
+    for num in &rel_numbers {
+        trace!("{:?}", num);
+    }
+    debug!("Determined that {} numbers are relevant.", rel_numbers.len());
+    // No schematic has enough i32s to overflow an i128.
+    Ok(rel_numbers.iter().map(|n| n.value as i128).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        let data = include_str!("../input2.txt");
+        let schem = Schematic::parse(data).unwrap();
+        let rel_numbers = query::part_numbers().run(&schem);
+        assert_eq!(rel_numbers.len(), 8);
+        assert_eq!(rel_numbers.iter().fold(0, |acc,x| acc+x.value), 4361);
+    }
+}
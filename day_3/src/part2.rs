@@ -0,0 +1,16 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::query;
+use crate::schematic::Schematic;
+
+pub fn solve(data: &str) -> Result<i128, ParseError> {
+    let schem = Schematic::parse(data)?;
+    let gears = query::gears().run(&schem);
+    for (gear, numbers) in &gears {
+        trace!("{:?} {:?}", gear, numbers);
+    }
+    // Products of two i32s, of which no schematic has enough to overflow an
+    // i128.
+    Ok(gears.iter().map(|(_, numbers)| numbers.iter().map(|n| n.value as i128).product::<i128>()).sum())
+}
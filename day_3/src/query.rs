@@ -0,0 +1,172 @@
+//! Queries over which numbers and symbols touch, of which both parts are one
+//! each.
+
+use crate::schematic::{Adjacency, Number, Schematic, Symbol};
+
+/// Which symbols a query looks at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Symbols {
+    Any,
+    OneOf(Vec<char>),
+}
+
+impl Symbols {
+    pub fn contains(&self, ch: char) -> bool {
+        match self {
+            Symbols::Any => true,
+            Symbols::OneOf(chars) => chars.contains(&ch),
+        }
+    }
+}
+
+/// How many neighbours a match needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Count {
+    pub fn allows(self, n: usize) -> bool {
+        match self {
+            Count::Exactly(k) => n == k,
+            Count::AtLeast(k) => n >= k,
+            Count::AtMost(k) => n <= k,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Product,
+}
+
+impl Aggregate {
+    /// `None` if the result doesn't fit in an `i128`.
+    pub fn apply(self, values: impl IntoIterator<Item = i128>) -> Option<i128> {
+        let mut values = values.into_iter();
+        match self {
+            Aggregate::Sum => values.try_fold(0i128, |acc, v| acc.checked_add(v)),
+            Aggregate::Product => values.try_fold(1i128, |acc, v| acc.checked_mul(v)),
+        }
+    }
+}
+
+/// Symbols from `symbols` with a `count` of numbers next to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolQuery {
+    pub symbols: Symbols,
+    pub adjacency: Adjacency,
+    pub count: Count,
+}
+
+impl SymbolQuery {
+    /// The numbers next to `symbol` if it matches.
+    pub fn matches<'a>(&self, schematic: &'a Schematic, symbol: &Symbol) -> Option<Vec<&'a Number>> {
+        if !self.symbols.contains(symbol.ch) {
+            return None;
+        }
+        let numbers = schematic.numbers_around(symbol, self.adjacency);
+        self.count.allows(numbers.len()).then_some(numbers)
+    }
+
+    pub fn run<'a>(&self, schematic: &'a Schematic) -> Vec<(&'a Symbol, Vec<&'a Number>)> {
+        schematic.symbols.iter().flatten().filter_map(|s| Some((s, self.matches(schematic, s)?))).collect()
+    }
+
+    /// Combines the numbers of each match with `each`, then the results with
+    /// `total`. `None` if anything on the way overflows.
+    pub fn aggregate(&self, schematic: &Schematic, each: Aggregate, total: Aggregate) -> Option<i128> {
+        let each = self
+            .run(schematic)
+            .iter()
+            .map(|(_, numbers)| each.apply(numbers.iter().map(|n| n.value as i128)))
+            .collect::<Option<Vec<_>>>()?;
+        total.apply(each)
+    }
+}
+
+/// Numbers with a `count` of symbols from `symbols` next to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberQuery {
+    pub symbols: Symbols,
+    pub adjacency: Adjacency,
+    pub count: Count,
+}
+
+impl NumberQuery {
+    /// The symbols from the set next to `number` if it matches.
+    pub fn matches<'a>(&self, schematic: &'a Schematic, number: &Number) -> Option<Vec<&'a Symbol>> {
+        let mut symbols = schematic.symbols_around(number, self.adjacency);
+        symbols.retain(|s| self.symbols.contains(s.ch));
+        self.count.allows(symbols.len()).then_some(symbols)
+    }
+
+    pub fn run<'a>(&self, schematic: &'a Schematic) -> Vec<&'a Number> {
+        schematic.numbers.iter().flatten().filter(|n| self.matches(schematic, n).is_some()).collect()
+    }
+
+    /// `None` if it overflows.
+    pub fn aggregate(&self, schematic: &Schematic, aggregate: Aggregate) -> Option<i128> {
+        aggregate.apply(self.run(schematic).iter().map(|n| n.value as i128))
+    }
+}
+
+/// Numbers touching any symbol, diagonals included.
+pub fn part_numbers() -> NumberQuery {
+    NumberQuery { symbols: Symbols::Any, adjacency: Adjacency::Diagonal, count: Count::AtLeast(1) }
+}
+
+/// '*' touching exactly two numbers.
+pub fn gears() -> SymbolQuery {
+    SymbolQuery { symbols: Symbols::OneOf(vec!['*']), adjacency: Adjacency::Diagonal, count: Count::Exactly(2) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_queries() {
+        let schematic = Schematic::parse(include_str!("../input2.txt")).unwrap();
+        assert_eq!(part_numbers().aggregate(&schematic, Aggregate::Sum), Some(4361));
+        assert_eq!(gears().aggregate(&schematic, Aggregate::Product, Aggregate::Sum), Some(467835));
+        assert_eq!(gears().run(&schematic).len(), 2);
+    }
+
+    #[test]
+    fn other_queries() {
+        let schematic = Schematic::parse("2.3#\n.*..\n4.+5\n").unwrap();
+        let crowded = SymbolQuery { symbols: Symbols::Any, adjacency: Adjacency::Diagonal, count: Count::AtLeast(3) };
+        let found = crowded.run(&schematic);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.ch, '*');
+        assert_eq!(crowded.aggregate(&schematic, Aggregate::Product, Aggregate::Sum), Some(24));
+
+        let lonely = SymbolQuery { count: Count::AtMost(1), ..crowded.clone() };
+        assert_eq!(lonely.run(&schematic).iter().map(|(s, _)| s.ch).collect::<String>(), "#+");
+
+        let next_to_hash = NumberQuery {
+            symbols: Symbols::OneOf(vec!['#', '+']),
+            adjacency: Adjacency::Orthogonal,
+            count: Count::AtLeast(1),
+        };
+        assert_eq!(next_to_hash.run(&schematic).iter().map(|n| n.value).collect::<Vec<_>>(), vec![3, 5]);
+        let diagonal = NumberQuery { adjacency: Adjacency::Diagonal, ..next_to_hash.clone() };
+        assert_eq!(diagonal.aggregate(&schematic, Aggregate::Sum), Some(8));
+        let untouched = NumberQuery { count: Count::Exactly(0), ..diagonal };
+        assert_eq!(untouched.aggregate(&schematic, Aggregate::Product), Some(8));
+    }
+
+    #[test]
+    fn overflow() {
+        let big = "2147483647";
+        let schematic = Schematic::parse(&[big, big, big, big, big].join(".")).unwrap();
+        let all = NumberQuery { symbols: Symbols::Any, adjacency: Adjacency::Diagonal, count: Count::Exactly(0) };
+        assert_eq!(all.aggregate(&schematic, Aggregate::Sum), Some(5 * i32::MAX as i128));
+        assert_eq!(all.aggregate(&schematic, Aggregate::Product), None);
+        assert_eq!(Aggregate::Sum.apply([i128::MAX, 1]), None);
+    }
+}
@@ -0,0 +1,350 @@
+use aoc_common::ParseError;
+
+use crate::query::{self, Aggregate};
+
+/// A run of digits. `col_range` is half open, the digits sit in `.0...1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: i32,
+    pub line: usize,
+    pub col_range: (usize, usize),
+}
+
+/// Anything that's neither a digit nor a '.'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub ch: char,
+    pub line: usize,
+    pub col: usize,
+}
+
+/// What sits in a cell, as an index into that line's numbers or symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Left, right, up and down.
+    Orthogonal,
+    /// The orthogonal cells and the four corners.
+    Diagonal,
+}
+
+impl Adjacency {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Adjacency::Orthogonal => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Adjacency::Diagonal => &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)],
+        }
+    }
+}
+
+/// One line's numbers, symbols and cells.
+type Scanned = (Vec<Number>, Vec<Symbol>, Vec<Cell>);
+
+/// The schematic with every number and symbol found, indexed by line, and
+/// a grid of cells pointing back at them.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub data: Vec<String>,
+    pub numbers: Vec<Vec<Number>>,
+    pub symbols: Vec<Vec<Symbol>>,
+    cells: Vec<Vec<Cell>>,
+    part_sum: i128,
+    gear_sum: i128,
+}
+
+impl Schematic {
+    pub fn new(data: Vec<String>) -> Result<Self, ParseError> {
+        let mut ret = Schematic { data, numbers: vec![], symbols: vec![], cells: vec![], part_sum: 0, gear_sum: 0 };
+        for (line, text) in ret.data.iter().enumerate() {
+            let (numbers, symbols, cells) = Schematic::scan_line(line, text)?;
+            ret.numbers.push(numbers);
+            ret.symbols.push(symbols);
+            ret.cells.push(cells);
+        }
+        (ret.part_sum, ret.gear_sum) = ret.sums(0..ret.data.len());
+        Ok(ret)
+    }
+
+    /// Fails on a number too big for an `i32`.
+    fn scan_line(line: usize, text: &str) -> Result<Scanned, ParseError> {
+        let mut numbers: Vec<Number> = vec![];
+        let mut symbols = vec![];
+        let mut cells = vec![Cell::Empty; text.chars().count()];
+        let mut digits = String::new();
+        for (col, ch) in text.chars().chain(std::iter::once('.')).enumerate() {
+            if ch.is_ascii_digit() {
+                digits.push(ch);
+            } else if !digits.is_empty() {
+                let start = col - digits.len();
+                let value = digits.parse().map_err(|_| ParseError::at(line + 1, start + 1, "smaller number", &digits))?;
+                cells[col - digits.len()..col].fill(Cell::Number(numbers.len()));
+                numbers.push(Number { value, line, col_range: (col - digits.len(), col) });
+                digits.clear();
+            }
+            if !ch.is_ascii_digit() && ch != '.' {
+                cells[col] = Cell::Symbol(symbols.len());
+                symbols.push(Symbol { ch, line, col });
+            }
+        }
+        Ok((numbers, symbols, cells))
+    }
+
+    /// Part numbers and gear ratios found on `lines`, summed. A sum of
+    /// products of two `i32`s fits in an `i128` however many there are.
+    fn sums(&self, lines: std::ops::Range<usize>) -> (i128, i128) {
+        let lines = lines.start..lines.end.min(self.data.len());
+        let parts = self.numbers[lines.clone()].iter().flatten().filter(|n| self.is_part_number(n)).map(|n| n.value as i128);
+        let ratios = self.symbols[lines].iter().flatten().filter_map(|s| self.gear_ratio(s));
+        (parts.sum(), ratios.sum())
+    }
+
+    /// Puts `ch` at `line`/`col`, which has to be inside the schematic.
+    ///
+    /// Only `line` is scanned again. Whether a number is a part number or a
+    /// symbol a gear can only change within a line of the edit, so the sums
+    /// are fixed up from the three lines around it. An edit that would make
+    /// a number too big is refused and leaves the schematic as it was.
+    pub fn set_cell(&mut self, line: usize, col: usize, ch: char) -> Result<(), ParseError> {
+        let mut chars = self.data[line].chars().collect::<Vec<_>>();
+        if chars[col] == ch {
+            return Ok(());
+        }
+        chars[col] = ch;
+        let text = chars.into_iter().collect::<String>();
+        let scanned = Schematic::scan_line(line, &text)?;
+
+        let around = line.saturating_sub(1)..line + 2;
+        let (parts, ratios) = self.sums(around.clone());
+        self.data[line] = text;
+        (self.numbers[line], self.symbols[line], self.cells[line]) = scanned;
+        let (new_parts, new_ratios) = self.sums(around);
+        self.part_sum += new_parts - parts;
+        self.gear_sum += new_ratios - ratios;
+        Ok(())
+    }
+
+    /// Sum of all part numbers, kept up to date by `set_cell`.
+    pub fn part_number_sum(&self) -> i128 {
+        self.part_sum
+    }
+
+    /// Sum of all gear ratios, kept up to date by `set_cell`.
+    pub fn gear_ratio_sum(&self) -> i128 {
+        self.gear_sum
+    }
+
+    /// Rows have to be equally wide and may only hold digits, '.' and
+    /// symbols, which are ASCII punctuation.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.first().ok_or_else(|| ParseError::end_of_input("a row", input))?.len();
+        for line in &lines {
+            if let Some((at, ch)) = line.char_indices().find(|&(_, c)| !(c.is_ascii_digit() || c == '.' || c.is_ascii_punctuation())) {
+                return Err(ParseError::new("a digit, '.' or a symbol", &line[at..at + ch.len_utf8()]).locate(input));
+            }
+            if width == 0 || line.len() != width {
+                let expected = if width == 0 { "a row".to_owned() } else { format!("{} columns", width) };
+                return Err(ParseError::new(expected, &line[width.min(line.len())..]).locate(input));
+            }
+        }
+        Schematic::new(lines.into_iter().map(|l| l.to_owned()).collect())
+    }
+
+    pub fn cell(&self, line: usize, col: usize) -> Cell {
+        self.cells.get(line).and_then(|l| l.get(col)).copied().unwrap_or(Cell::Empty)
+    }
+
+    /// Cells next to `line`/`col` that are inside the schematic.
+    fn neighbours(&self, line: usize, col: usize, adjacency: Adjacency) -> impl Iterator<Item = (usize, usize)> + '_ {
+        adjacency.offsets().iter().filter_map(move |&(dl, dc)| {
+            let (line, col) = (line.checked_add_signed(dl)?, col.checked_add_signed(dc)?);
+            (col < self.cells.get(line)?.len()).then_some((line, col))
+        })
+    }
+
+    /// Numbers next to `symbol`, each once, in reading order.
+    pub fn numbers_around(&self, symbol: &Symbol, adjacency: Adjacency) -> Vec<&Number> {
+        let mut ids = self
+            .neighbours(symbol.line, symbol.col, adjacency)
+            .filter_map(|(line, col)| match self.cells[line][col] {
+                Cell::Number(idx) => Some((line, idx)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_iter().map(|(line, idx)| &self.numbers[line][idx]).collect()
+    }
+
+    /// Symbols next to any digit of `number`, each once, in reading order.
+    pub fn symbols_around(&self, number: &Number, adjacency: Adjacency) -> Vec<&Symbol> {
+        let mut ids = (number.col_range.0..number.col_range.1)
+            .flat_map(|col| self.neighbours(number.line, col, adjacency))
+            .filter_map(|(line, col)| match self.cells[line][col] {
+                Cell::Symbol(idx) => Some((line, idx)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_iter().map(|(line, idx)| &self.symbols[line][idx]).collect()
+    }
+
+    /// Part 1's rule, see `query::part_numbers`.
+    pub fn is_part_number(&self, number: &Number) -> bool {
+        query::part_numbers().matches(self, number).is_some()
+    }
+
+    /// Part 2's rule, see `query::gears`.
+    pub fn gear_ratio(&self, symbol: &Symbol) -> Option<i128> {
+        let numbers = query::gears().matches(self, symbol)?;
+        // Two numbers, so this always fits.
+        Aggregate::Product.apply(numbers.iter().map(|n| n.value as i128))
+    }
+
+    pub fn number_at(&self, line: usize, col: usize) -> Option<&Number> {
+        match self.cell(line, col) {
+            Cell::Number(idx) => Some(&self.numbers[line][idx]),
+            _ => None,
+        }
+    }
+
+    pub fn symbol_at(&self, line: usize, col: usize) -> Option<&Symbol> {
+        match self.cell(line, col) {
+            Cell::Symbol(idx) => Some(&self.symbols[line][idx]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let schematic = Schematic::parse(include_str!("../input2.txt")).unwrap();
+        let parts = schematic.numbers.iter().flatten().filter(|n| schematic.is_part_number(n)).map(|n| n.value);
+        assert_eq!(parts.sum::<i32>(), 4361);
+        let ratios = schematic.symbols.iter().flatten().filter_map(|s| schematic.gear_ratio(s));
+        assert_eq!(ratios.sum::<i128>(), 467835);
+        assert_eq!((schematic.part_number_sum(), schematic.gear_ratio_sum()), (4361, 467835));
+
+        let star = schematic.symbol_at(1, 3).unwrap();
+        assert_eq!(schematic.numbers_around(star, Adjacency::Diagonal).iter().map(|n| n.value).collect::<Vec<_>>(), vec![467, 35]);
+        assert_eq!(schematic.number_at(0, 2).unwrap().value, 467);
+        assert_eq!(schematic.number_at(0, 3), None);
+        assert!(!schematic.is_part_number(schematic.number_at(0, 5).unwrap()));
+    }
+
+    #[test]
+    fn numbers_at_line_ends() {
+        let schematic = Schematic::parse("12.\n.+3\n").unwrap();
+        assert_eq!(schematic.numbers[1][0].col_range, (2, 3));
+        assert!(schematic.is_part_number(&schematic.numbers[0][0]));
+        assert!(schematic.is_part_number(&schematic.numbers[1][0]));
+    }
+
+    #[test]
+    fn orthogonal() {
+        let schematic = Schematic::parse("1.2\n.*.\n3.4\n.5.\n").unwrap();
+        let star = schematic.symbol_at(1, 1).unwrap();
+        assert_eq!(schematic.numbers_around(star, Adjacency::Diagonal).len(), 4);
+        assert!(schematic.numbers_around(star, Adjacency::Orthogonal).is_empty());
+        let five = schematic.number_at(3, 1).unwrap();
+        assert!(schematic.symbols_around(five, Adjacency::Orthogonal).is_empty());
+        assert_eq!(schematic.cell(1, 1), Cell::Symbol(0));
+        assert_eq!(schematic.cell(2, 2), Cell::Number(1));
+    }
+
+    #[test]
+    fn set_cell() {
+        let mut schematic = Schematic::parse(include_str!("../input2.txt")).unwrap();
+        // Splits 467 into a part number 4 and 7, which isn't.
+        schematic.set_cell(0, 1, '.').unwrap();
+        assert_eq!(schematic.data[0], "4.7..114..");
+        assert_eq!(schematic.numbers[0].iter().map(|n| n.value).collect::<Vec<_>>(), vec![4, 7, 114]);
+        assert_eq!(schematic.part_number_sum(), 4361 - 467 + 7);
+        assert_eq!(schematic.gear_ratio_sum(), 467835 - 467 * 35 + 7 * 35);
+        // Touching 114 makes it a part number.
+        schematic.set_cell(1, 4, '#').unwrap();
+        assert_eq!(schematic.part_number_sum(), 4361 - 467 + 7 + 114);
+        // A third number next to the '*' means it's no gear any more.
+        schematic.set_cell(1, 2, '1').unwrap();
+        assert_eq!(schematic.part_number_sum(), 4361 - 467 + 7 + 114 + 1);
+        assert_eq!(schematic.gear_ratio_sum(), 467835 - 467 * 35);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        let e = Schematic::parse("hello world").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 1, "h"));
+        let e = Schematic::parse("12.\n.+ 3\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, " "));
+        let e = Schematic::parse("12.\n.+\n").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 3: expected 3 columns, found end of line");
+        assert_eq!(Schematic::parse("").unwrap_err().to_string(), "line 1, column 1: expected a row, found end of input");
+        let e = Schematic::parse("............\n.99999999999\n").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 2: expected smaller number, found '99999999999'");
+    }
+
+    /// Random edits from `chars` to `data`, each checked against parsing the
+    /// result from scratch. Edits making a number too big must change nothing.
+    fn check_edits(data: Vec<String>, chars: &[char], mut state: u64) {
+        let mut next = |n: usize| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+        let (lines, cols) = (data.len(), data[0].len());
+        let mut schematic = Schematic::new(data).unwrap();
+        for _ in 0..2000 {
+            let (line, col, ch) = (next(lines), next(cols), chars[next(chars.len())]);
+            let before = schematic.data.clone();
+            if schematic.set_cell(line, col, ch).is_err() {
+                assert_eq!(schematic.data, before);
+            }
+            let rebuilt = Schematic::new(schematic.data.clone()).unwrap();
+            assert_eq!(schematic.numbers, rebuilt.numbers);
+            assert_eq!(schematic.symbols, rebuilt.symbols);
+            assert_eq!(schematic.cells, rebuilt.cells);
+            assert_eq!(schematic.part_number_sum(), rebuilt.part_number_sum());
+            assert_eq!(schematic.gear_ratio_sum(), rebuilt.gear_ratio_sum());
+            assert_eq!(schematic.part_number_sum(), query::part_numbers().aggregate(&rebuilt, Aggregate::Sum).unwrap());
+        }
+    }
+
+    #[test]
+    fn set_cell_matches_rebuild() {
+        const CHARS: &[char] = &['.', '.', '.', '.', '0', '1', '2', '5', '7', '9', '*', '*', '#', '+'];
+        let mut state = 42u64;
+        let data = (0..9)
+            .map(|_| {
+                (0..11)
+                    .map(|_| {
+                        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                        CHARS[(state >> 33) as usize % CHARS.len()]
+                    })
+                    .collect()
+            })
+            .collect();
+        check_edits(data, CHARS, state);
+    }
+
+    /// Gears between the biggest numbers there are, whose ratios add up past
+    /// an `i64`.
+    #[test]
+    fn set_cell_with_huge_numbers() {
+        let data = (0..16).map(|l| if l % 4 == 1 { "2147483647*2147483647" } else { "...........*........." }.to_owned()).collect::<Vec<_>>();
+        let schematic = Schematic::new(data.clone()).unwrap();
+        let ratio = i32::MAX as i128 * i32::MAX as i128;
+        assert_eq!(schematic.gear_ratio_sum(), 4 * ratio);
+        assert!(schematic.gear_ratio_sum() > i64::MAX as i128);
+        check_edits(data, &['.', '*', '#', '1', '7'], 5);
+    }
+}
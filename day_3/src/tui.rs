@@ -0,0 +1,273 @@
+//! Scrollable terminal viewer for schematics.
+//!
+//! `Viewer` holds the cursor and scroll position and renders frames as plain
+//! strings with escape codes, `run` wires it up to a raw mode terminal.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+use termion::screen::IntoAlternateScreen;
+use termion::{clear, color, cursor, style};
+
+use crate::schematic::{Adjacency, Schematic};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Empty,
+    PartNumber,
+    OtherNumber,
+    Symbol,
+    Gear,
+}
+
+impl Kind {
+    fn colour(self) -> String {
+        match self {
+            Kind::Empty => color::Fg(color::LightBlack).to_string(),
+            Kind::PartNumber => color::Fg(color::Green).to_string(),
+            Kind::OtherNumber => color::Fg(color::Red).to_string(),
+            Kind::Symbol => color::Fg(color::Yellow).to_string(),
+            Kind::Gear => format!("{}{}", color::Fg(color::LightMagenta), style::Bold),
+        }
+    }
+}
+
+pub struct Viewer<'a> {
+    schematic: &'a Schematic,
+    kinds: Vec<Vec<Kind>>,
+    /// Line and column, 0-based.
+    pub cursor: (usize, usize),
+    /// First line and column on screen.
+    pub scroll: (usize, usize),
+}
+
+impl<'a> Viewer<'a> {
+    pub fn new(schematic: &'a Schematic) -> Self {
+        let mut kinds = schematic.data.iter().map(|l| vec![Kind::Empty; l.chars().count()]).collect::<Vec<_>>();
+        for number in schematic.numbers.iter().flatten() {
+            let kind = if schematic.is_part_number(number) { Kind::PartNumber } else { Kind::OtherNumber };
+            kinds[number.line][number.col_range.0..number.col_range.1].fill(kind);
+        }
+        for symbol in schematic.symbols.iter().flatten() {
+            kinds[symbol.line][symbol.col] = if schematic.gear_ratio(symbol).is_some() { Kind::Gear } else { Kind::Symbol };
+        }
+        Viewer { schematic, kinds, cursor: (0, 0), scroll: (0, 0) }
+    }
+
+    pub fn kind(&self, line: usize, col: usize) -> Kind {
+        self.kinds.get(line).and_then(|l| l.get(col)).copied().unwrap_or(Kind::Empty)
+    }
+
+    fn width(&self, line: usize) -> usize {
+        self.kinds.get(line).map_or(0, |l| l.len())
+    }
+
+    pub fn move_to(&mut self, line: usize, col: usize) {
+        let line = line.min(self.kinds.len().saturating_sub(1));
+        self.cursor = (line, col.min(self.width(line).saturating_sub(1)));
+    }
+
+    fn move_by(&mut self, lines: isize, cols: isize) {
+        let line = self.cursor.0.saturating_add_signed(lines);
+        let col = self.cursor.1.saturating_add_signed(cols);
+        self.move_to(line, col);
+    }
+
+    /// Jumps to the next (or previous) symbol in reading order.
+    fn jump_symbol(&mut self, forward: bool) {
+        let here = self.cursor;
+        let symbols = self.schematic.symbols.iter().flatten().map(|s| (s.line, s.col));
+        let target = if forward { symbols.clone().find(|&p| p > here) } else { symbols.clone().rfind(|&p| p < here) };
+        if let Some((line, col)) = target {
+            self.move_to(line, col);
+        }
+    }
+
+    /// Applies a key press, `false` means quit. `page` is how many lines
+    /// fit on screen.
+    pub fn handle(&mut self, key: Key, page: usize) -> bool {
+        let page = page.max(1) as isize;
+        match key {
+            Key::Char('q') | Key::Esc | Key::Ctrl('c') => return false,
+            Key::Up | Key::Char('k') => self.move_by(-1, 0),
+            Key::Down | Key::Char('j') => self.move_by(1, 0),
+            Key::Left | Key::Char('h') => self.move_by(0, -1),
+            Key::Right | Key::Char('l') => self.move_by(0, 1),
+            Key::PageUp => self.move_by(-page, 0),
+            Key::PageDown | Key::Char(' ') => self.move_by(page, 0),
+            Key::Home | Key::Char('0') => self.move_to(self.cursor.0, 0),
+            Key::End | Key::Char('$') => self.move_to(self.cursor.0, usize::MAX),
+            Key::Char('g') => self.move_to(0, self.cursor.1),
+            Key::Char('G') => self.move_to(usize::MAX, self.cursor.1),
+            Key::Char('n') | Key::Char('\t') => self.jump_symbol(true),
+            Key::Char('N') | Key::BackTab => self.jump_symbol(false),
+            _ => {}
+        }
+        true
+    }
+
+    /// Scrolls just enough to keep the cursor inside a `rows` x `cols` view.
+    pub fn follow_cursor(&mut self, rows: usize, cols: usize) {
+        let (rows, cols) = (rows.max(1), cols.max(1));
+        let (line, col) = self.cursor;
+        if line < self.scroll.0 {
+            self.scroll.0 = line;
+        } else if line >= self.scroll.0 + rows {
+            self.scroll.0 = line + 1 - rows;
+        }
+        if col < self.scroll.1 {
+            self.scroll.1 = col;
+        } else if col >= self.scroll.1 + cols {
+            self.scroll.1 = col + 1 - cols;
+        }
+    }
+
+    /// What's under the cursor and what it touches.
+    pub fn details(&self) -> String {
+        let (line, col) = self.cursor;
+        let at = |l: usize, c: usize| format!("{}:{}", l + 1, c + 1);
+        if let Some(number) = self.schematic.number_at(line, col) {
+            let symbols = self.schematic.symbols_around(number, Adjacency::Diagonal);
+            let place = format!("{} at {}-{}", number.value, at(number.line, number.col_range.0), number.col_range.1);
+            if symbols.is_empty() {
+                return format!("{}, not a part number", place);
+            }
+            let touching = symbols.iter().map(|s| format!("{} at {}", s.ch, at(s.line, s.col))).collect::<Vec<_>>();
+            return format!("{}, part number next to {}", place, touching.join(", "));
+        }
+        if let Some(symbol) = self.schematic.symbol_at(line, col) {
+            let numbers = self.schematic.numbers_around(symbol, Adjacency::Diagonal);
+            let mut ret = format!("{} at {}", symbol.ch, at(line, col));
+            if numbers.is_empty() {
+                ret.push_str(" touches no numbers");
+            } else {
+                let values = numbers.iter().map(|n| n.value.to_string()).collect::<Vec<_>>();
+                write!(ret, " touches {}", values.join(", ")).unwrap();
+            }
+            if let Some(ratio) = self.schematic.gear_ratio(symbol) {
+                write!(ret, ", gear with ratio {}", ratio).unwrap();
+            }
+            return ret;
+        }
+        at(line, col)
+    }
+
+    /// One full frame for a terminal of `rows` x `cols`, the last row being
+    /// the status line. Terminal coordinates are 1-based.
+    pub fn render(&mut self, rows: usize, cols: usize) -> String {
+        let view_rows = rows.saturating_sub(1).max(1);
+        self.follow_cursor(view_rows, cols);
+        let mut ret = String::new();
+        for row in 0..view_rows {
+            let line = self.scroll.0 + row;
+            write!(ret, "{}{}", cursor::Goto(1, row as u16 + 1), clear::CurrentLine).unwrap();
+            let Some(text) = self.schematic.data.get(line) else {
+                continue;
+            };
+            for (col, ch) in text.chars().enumerate().skip(self.scroll.1).take(cols) {
+                ret.push_str(&self.kind(line, col).colour());
+                if (line, col) == self.cursor {
+                    write!(ret, "{}", style::Invert).unwrap();
+                }
+                write!(ret, "{}{}", ch, style::Reset).unwrap();
+            }
+        }
+        let status = format!("{} | {}", self.details(), "arrows/hjkl move, n/N next symbol, q quit");
+        write!(ret, "{}{}{}", cursor::Goto(1, rows as u16), clear::CurrentLine, style::Invert).unwrap();
+        ret.extend(status.chars().take(cols));
+        write!(ret, "{}", style::Reset).unwrap();
+        ret
+    }
+}
+
+/// Shows the schematic until the user quits.
+pub fn run(schematic: &Schematic) -> io::Result<()> {
+    let mut screen = io::stdout().into_raw_mode()?.into_alternate_screen()?;
+    write!(screen, "{}", cursor::Hide)?;
+    let mut viewer = Viewer::new(schematic);
+    let mut keys = io::stdin().keys();
+    loop {
+        let (cols, rows) = termion::terminal_size()?;
+        write!(screen, "{}", viewer.render(rows as usize, cols as usize))?;
+        screen.flush()?;
+        let Some(key) = keys.next() else {
+            break;
+        };
+        if !viewer.handle(key?, rows.saturating_sub(1) as usize) {
+            break;
+        }
+    }
+    write!(screen, "{}", cursor::Show)?;
+    screen.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Schematic {
+        Schematic::parse(include_str!("../input2.txt")).unwrap()
+    }
+
+    #[test]
+    fn kinds() {
+        let schematic = example();
+        let viewer = Viewer::new(&schematic);
+        assert_eq!(viewer.kind(0, 0), Kind::PartNumber);
+        assert_eq!(viewer.kind(0, 5), Kind::OtherNumber);
+        assert_eq!(viewer.kind(0, 3), Kind::Empty);
+        assert_eq!(viewer.kind(1, 3), Kind::Gear);
+        assert_eq!(viewer.kind(3, 6), Kind::Symbol);
+        // '*' next to a single number isn't a gear.
+        assert_eq!(viewer.kind(4, 3), Kind::Symbol);
+    }
+
+    #[test]
+    fn details() {
+        let schematic = example();
+        let mut viewer = Viewer::new(&schematic);
+        viewer.move_to(1, 3);
+        assert_eq!(viewer.details(), "* at 2:4 touches 467, 35, gear with ratio 16345");
+        viewer.move_to(0, 1);
+        assert_eq!(viewer.details(), "467 at 1:1-3, part number next to * at 2:4");
+        viewer.move_to(0, 6);
+        assert_eq!(viewer.details(), "114 at 1:6-8, not a part number");
+    }
+
+    #[test]
+    fn navigation_and_scrolling() {
+        let schematic = example();
+        let mut viewer = Viewer::new(&schematic);
+        viewer.handle(Key::Char('n'), 3);
+        assert_eq!(viewer.cursor, (1, 3));
+        viewer.handle(Key::Char('n'), 3);
+        assert_eq!(viewer.cursor, (3, 6));
+        viewer.handle(Key::Char('N'), 3);
+        assert_eq!(viewer.cursor, (1, 3));
+        viewer.handle(Key::Char('G'), 3);
+        assert_eq!(viewer.cursor, (9, 3));
+        viewer.render(4, 5);
+        // 3 lines of schematic fit above the status line.
+        assert_eq!(viewer.scroll, (7, 0));
+        viewer.handle(Key::End, 3);
+        viewer.render(4, 5);
+        assert_eq!((viewer.cursor, viewer.scroll), ((9, 9), (7, 5)));
+        viewer.handle(Key::Left, 3);
+        viewer.handle(Key::PageUp, 3);
+        assert_eq!(viewer.cursor, (6, 8));
+        assert!(!viewer.handle(Key::Char('q'), 3));
+    }
+
+    #[test]
+    fn render_uses_one_based_coordinates() {
+        let schematic = example();
+        let mut viewer = Viewer::new(&schematic);
+        let frame = viewer.render(4, 5);
+        assert!(frame.starts_with(&cursor::Goto(1, 1).to_string()));
+        assert!(frame.contains(&cursor::Goto(1, 4).to_string()));
+        assert!(!frame.contains("114"));
+    }
+}
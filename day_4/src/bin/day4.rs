@@ -0,0 +1,81 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use day_4::card::parse_cards;
+use day_4::cascade::cascade;
+
+/// Where all those scratchcard copies came from
+#[derive(Parser)]
+struct Cli {
+    /// Scratchcard table to read
+    #[arg(short, long, default_value = "input.txt")]
+    input: String,
+    #[command(subcommand)]
+    query: Query,
+}
+
+#[derive(Subcommand)]
+enum Query {
+    /// Copies of every card, and the total
+    Copies,
+    /// Which cards' wins the copies of a card came from
+    Explain {
+        card: u32,
+        /// How many levels of sources to follow
+        #[arg(short, long, default_value_t = 2)]
+        depth: usize,
+    },
+    /// The whole graph of which card copied which
+    Export {
+        #[arg(short, long, value_enum, default_value_t = Format::Dot)]
+        format: Format,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Graphviz
+    Dot,
+    Json,
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let cli = Cli::parse();
+    let text = match std::fs::read_to_string(&cli.input) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: {}: {}", cli.input, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let cards = match parse_cards(&text) {
+        Ok(cards) => cards,
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(&cli.input));
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = cascade(&cards, !matches!(cli.query, Query::Copies));
+    let output = match cli.query {
+        Query::Copies => {
+            for (number, copies) in result.numbers.iter().zip(&result.copies) {
+                println!("Card {}: {}", number, copies);
+            }
+            Ok(format!("Total: {}\n", result.total()))
+        }
+        Query::Explain { card, depth } => result.explain(card, depth),
+        Query::Export { format: Format::Dot } => result.to_dot(),
+        Query::Export { format: Format::Json } => result.to_json().map(|json| format!("{}\n", serde_json::to_string_pretty(&json).unwrap())),
+    };
+    match output {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use aoc_common::{ParseError, Scanner};
+use tracing::trace;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub number: u32,
+    pub winning: Vec<u32>,
+    pub have: Vec<u32>,
+}
+
+impl Card {
+    // Card   1: 41 48 83 | 83 86  6
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        trace!("Doing '{}'", s);
+        let mut s = Scanner::new(s);
+        s.tag("Card")?;
+        s.spaces();
+        let number = s.unsigned()?;
+        s.tag(":")?;
+        let winning = s.numbers()?;
+        s.spaces();
+        s.tag("|")?;
+        let have = s.numbers()?;
+        s.end()?;
+        Ok(Card { number, winning, have })
+    }
+
+    /// How many of the numbers we have are winning ones.
+    pub fn matches(&self) -> usize {
+        let winning = self.winning.iter().collect::<HashSet<_>>();
+        self.have.iter().filter(|num| winning.contains(num)).count()
+    }
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.split('\n').filter(|l| !l.is_empty()).map(Card::new).collect::<Result<Vec<_>, _>>().map_err(|e| e.locate(input))
+}
@@ -0,0 +1,166 @@
+//! How many copies of each card we end up with, and where they came from.
+//!
+//! A card with `m` matches wins one copy of each of the next `m` cards per
+//! copy of itself. Wins only ever go forward, so by the time a card comes up
+//! its count is final. Wins running past the last card are dropped.
+
+use std::fmt::Write;
+
+use serde_json::{json, Value};
+
+use crate::card::Card;
+
+#[derive(Debug, Clone)]
+pub struct Cascade {
+    /// Card numbers, in table order.
+    pub numbers: Vec<u32>,
+    pub matches: Vec<usize>,
+    pub copies: Vec<u64>,
+    /// Per card, the positions of the cards whose wins copied it. Each of
+    /// them gave as many copies as it had itself.
+    pub sources: Option<Vec<Vec<usize>>>,
+}
+
+/// Runs the cascade, keeping track of `sources` if `record` is set.
+pub fn cascade(cards: &[Card], record: bool) -> Cascade {
+    let n = cards.len();
+    let matches = cards.iter().map(|c| c.matches()).collect::<Vec<_>>();
+    let mut copies = vec![0; n];
+    // Copies won for every card from here on, less what runs out before it.
+    let mut won = 0u64;
+    let mut runs_out = vec![0; n + 1];
+    let mut sources = record.then(|| vec![vec![]; n]);
+    for i in 0..n {
+        won -= runs_out[i];
+        copies[i] = 1 + won;
+        let end = (i + 1 + matches[i]).min(n);
+        if end > i + 1 {
+            won += copies[i];
+            runs_out[end] += copies[i];
+        }
+        if let Some(sources) = &mut sources {
+            for targets in &mut sources[i + 1..end] {
+                targets.push(i);
+            }
+        }
+    }
+    Cascade { numbers: cards.iter().map(|c| c.number).collect(), matches, copies, sources }
+}
+
+impl Cascade {
+    pub fn total(&self) -> u64 {
+        self.copies.iter().sum()
+    }
+
+    pub fn position(&self, number: u32) -> Option<usize> {
+        self.numbers.iter().position(|&n| n == number)
+    }
+
+    fn sources(&self) -> Result<&[Vec<usize>], String> {
+        self.sources.as_deref().ok_or_else(|| "provenance wasn't recorded".to_owned())
+    }
+
+    /// Where the copies of card `number` came from, following the biggest
+    /// contributors first, `depth` levels deep.
+    pub fn explain(&self, number: u32, depth: usize) -> Result<String, String> {
+        let sources = self.sources()?;
+        let card = self.position(number).ok_or_else(|| format!("no card {}", number))?;
+        let mut ret = format!("Card {}: {} copies, 1 original\n", number, self.copies[card]);
+        self.explain_sources(sources, card, depth, 1, &mut ret);
+        Ok(ret)
+    }
+
+    fn explain_sources(&self, sources: &[Vec<usize>], card: usize, depth: usize, indent: usize, out: &mut String) {
+        if indent > depth {
+            if !sources[card].is_empty() {
+                writeln!(out, "{:1$}...", "", indent * 2).unwrap();
+            }
+            return;
+        }
+        let mut from = sources[card].clone();
+        from.sort_by_key(|&i| (std::cmp::Reverse(self.copies[i]), i));
+        for i in from {
+            let share = self.copies[i] as f64 / self.copies[card] as f64 * 100.0;
+            writeln!(out, "{:1$}{2} from card {3} ({4:.1}%)", "", indent * 2, self.copies[i], self.numbers[i], share).unwrap();
+            self.explain_sources(sources, i, depth, indent + 1, out);
+        }
+    }
+
+    /// Graphviz digraph with an edge from each card to the cards it copied,
+    /// labelled with the copies given.
+    pub fn to_dot(&self) -> Result<String, String> {
+        let sources = self.sources()?;
+        let mut ret = String::from("digraph cards {\n");
+        for (i, number) in self.numbers.iter().enumerate() {
+            writeln!(ret, "  c{} [label=\"{}\\n{} copies\"];", number, number, self.copies[i]).unwrap();
+        }
+        for (target, from) in sources.iter().enumerate() {
+            for &i in from {
+                writeln!(ret, "  c{} -> c{} [label=\"{}\"];", self.numbers[i], self.numbers[target], self.copies[i]).unwrap();
+            }
+        }
+        ret.push_str("}\n");
+        Ok(ret)
+    }
+
+    pub fn to_json(&self) -> Result<Value, String> {
+        let sources = self.sources()?;
+        let cards = (0..self.numbers.len())
+            .map(|i| {
+                json!({
+                    "card": self.numbers[i],
+                    "matches": self.matches[i],
+                    "copies": self.copies[i],
+                    "copied_by": sources[i].iter().map(|&s| self.numbers[s]).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        Ok(json!({ "total": self.total(), "cards": cards }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+
+    fn example() -> Vec<Card> {
+        parse_cards(include_str!("../input2.txt")).unwrap()
+    }
+
+    #[test]
+    fn copies() {
+        let result = cascade(&example(), false);
+        assert_eq!(result.copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(result.total(), 30);
+        assert!(result.sources.is_none());
+        assert!(result.explain(5, 1).is_err());
+    }
+
+    #[test]
+    fn wins_past_the_end() {
+        let cards = parse_cards("Card 1: 1 2 | 1 2\nCard 2: 3 4 5 | 3 4 5\n").unwrap();
+        let result = cascade(&cards, true);
+        assert_eq!(result.copies, vec![1, 2]);
+        assert_eq!(result.sources.unwrap(), vec![vec![], vec![0]]);
+    }
+
+    #[test]
+    fn provenance() {
+        let result = cascade(&example(), true);
+        let sources = result.sources.as_ref().unwrap();
+        assert_eq!(sources[4], vec![0, 2, 3]);
+        for (i, from) in sources.iter().enumerate() {
+            assert_eq!(result.copies[i], 1 + from.iter().map(|&s| result.copies[s]).sum::<u64>());
+        }
+        let explained = result.explain(5, 1).unwrap();
+        assert_eq!(
+            explained,
+            "Card 5: 14 copies, 1 original\n  8 from card 4 (57.1%)\n    ...\n  4 from card 3 (28.6%)\n    ...\n  1 from card 1 (7.1%)\n"
+        );
+        assert!(result.explain(7, 1).unwrap_err().contains("no card 7"));
+        assert!(result.to_dot().unwrap().contains("c4 -> c5 [label=\"8\"];"));
+        let json = result.to_json().unwrap();
+        assert_eq!(json["cards"][4], json!({ "card": 5, "matches": 0, "copies": 14, "copied_by": [1, 3, 4] }));
+    }
+}
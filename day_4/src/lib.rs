@@ -0,0 +1,21 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod card;
+pub mod cascade;
+pub mod part1;
+pub mod part2;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn solve_part1(input: &str) -> Result<u32, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<usize, ParseError> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,18 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::card::parse_cards;
+
+pub fn solve(input: &str) -> Result<u32, ParseError> {
+    let tickets = parse_cards(input)?;
+    let mut sum = 0;
+    for ticket in &tickets {
+        trace!("{:?}", ticket);
+        let cnt = ticket.matches();
+        if cnt == 0 {
+            continue;
+        }
+        sum += 2u32.pow(cnt as u32 - 1);
+    }
+    Ok(sum)
+}
@@ -0,0 +1,13 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::card::parse_cards;
+use crate::cascade::cascade;
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let tickets = parse_cards(input)?;
+    for ticket in &tickets {
+        trace!("{:?}", ticket);
+    }
+    Ok(cascade(&tickets, false).total() as usize)
+}
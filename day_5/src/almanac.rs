@@ -0,0 +1,258 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{ParseError, Scanner};
+use tracing::trace;
+
+use crate::interval::IntervalMap;
+
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub from: String,
+    pub to: String,
+    /// `(dest, src, len)` as written.
+    pub ranges: Vec<(usize, usize, usize)>,
+}
+
+impl Mapping {
+    // seed-to-soil map:
+    // 50 98 2
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let mut lines = s.lines();
+        let mut header = Scanner::new(lines.next().unwrap_or_default());
+        let from = header.word()?.to_owned();
+        header.tag("-to-")?;
+        let to = header.word()?.to_owned();
+        header.tag(" map:")?;
+        header.end()?;
+        let mut ranges = vec![];
+        for line in lines.filter(|l| !l.is_empty()) {
+            let mut s = Scanner::new(line);
+            let dest_idx = s.unsigned()?;
+            s.tag(" ")?;
+            let source_idx = s.unsigned()?;
+            s.tag(" ")?;
+            let len_range = s.unsigned()?;
+            s.end()?;
+            ranges.push((dest_idx, source_idx, len_range));
+        }
+        Ok(Mapping { from, to, ranges })
+    }
+
+    /// Straight from the puzzle: the entry covering `x`, or `x` itself.
+    /// Where entries overlap the one starting first wins, the earlier one if
+    /// they start together. Entries don't cover values they'd send to
+    /// `usize::MAX` or past it.
+    /// Doesn't go near `IntervalMap`, so the two can check each other.
+    pub fn get(&self, x: usize) -> usize {
+        self.ranges
+            .iter()
+            .filter(|&&(dest, src, len)| x >= src && x - src < len && x - src < usize::MAX - dest)
+            .min_by_key(|&&(_, src, _)| src)
+            .map_or(x, |&(dest, src, _)| dest + (x - src))
+    }
+
+    pub fn interval_map(&self) -> IntervalMap {
+        IntervalMap::new(self.ranges.iter().copied())
+    }
+}
+
+/// How to read the numbers after "seeds:".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seeds {
+    /// Each is a seed.
+    Single,
+    /// Pairs of start and length.
+    Ranges,
+}
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    /// Half open, single seeds are ranges of one.
+    pub seeds: Vec<(usize, usize)>,
+    pub mappings: Vec<Mapping>,
+}
+
+fn parse_seeds(s: &str, kind: Seeds) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut s = Scanner::new(s);
+    s.tag("seeds:")?;
+    let mut ranges = vec![];
+    loop {
+        s.spaces();
+        if !s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            break;
+        }
+        let start_at = s;
+        let start: usize = s.unsigned()?;
+        let range = match kind {
+            Seeds::Single => start.checked_add(1).ok_or_else(|| start_at.error("smaller number"))?,
+            Seeds::Ranges => {
+                s.spaces();
+                if !s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    return Err(s.error("length of the last seed range"));
+                }
+                let len_at = s;
+                let len: usize = s.unsigned()?;
+                start.checked_add(len).ok_or_else(|| len_at.error("a seed range ending before usize::MAX"))?
+            }
+        };
+        ranges.push((start, range));
+    }
+    s.end()?;
+    if ranges.iter().all(|(start, end)| start == end) {
+        return Err(s.error("at least one seed"));
+    }
+    Ok(ranges)
+}
+
+impl Almanac {
+    pub fn parse(content: &str, seeds: Seeds) -> Result<Self, ParseError> {
+        let mut categories = content.split("\n\n");
+        let seeds = parse_seeds(categories.next().unwrap_or_default(), seeds).map_err(|e| e.locate(content))?;
+        let mappings = categories.map(|cat| Mapping::new(cat).map_err(|e| e.locate(content))).collect::<Result<Vec<_>, _>>()?;
+        for m in &mappings {
+            trace!("{:?}", m);
+        }
+        Ok(Almanac { seeds, mappings })
+    }
+
+    /// The mappings from "seed" on, for as long as the next category has one.
+    pub fn chain(&self) -> Vec<&Mapping> {
+        let mut ret = vec![];
+        let mut src_cat = "seed";
+        while let Some(m) = self.mappings.iter().find(|m| m.from == src_cat) {
+            // Going round in circles.
+            if ret.len() == self.mappings.len() {
+                break;
+            }
+            ret.push(m);
+            src_cat = m.to.as_str();
+        }
+        ret
+    }
+
+    /// Seed to whatever the chain ends in, as one map.
+    pub fn seed_map(&self) -> IntervalMap {
+        self.chain().iter().fold(IntervalMap::identity(), |acc, m| acc.then(&m.interval_map()))
+    }
+
+    /// Every category in order of first appearance.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut ret = vec![];
+        for name in self.mappings.iter().flat_map(|m| [m.from.as_str(), m.to.as_str()]) {
+            if !ret.contains(&name) {
+                ret.push(name);
+            }
+        }
+        ret
+    }
+
+    /// Fewest mappings leading from category `from` to `to`.
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&Mapping>, String> {
+        if !self.categories().contains(&from) {
+            return Err(format!("no category {}", from));
+        }
+        // Mapping that first reached each category.
+        let mut reached_by = HashMap::<&str, Option<&Mapping>>::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(cat) = queue.pop_front() {
+            if cat == to {
+                let mut ret = vec![];
+                let mut at = to;
+                while let Some(m) = reached_by[at] {
+                    ret.push(m);
+                    at = m.from.as_str();
+                }
+                ret.reverse();
+                return Ok(ret);
+            }
+            for m in self.mappings.iter().filter(|m| m.from == cat) {
+                reached_by.entry(m.to.as_str()).or_insert_with(|| {
+                    queue.push_back(m.to.as_str());
+                    Some(m)
+                });
+            }
+        }
+        Err(format!("no way from {} to {}", from, to))
+    }
+
+    /// Category `from` to `to` as one map.
+    pub fn map_between(&self, from: &str, to: &str) -> Result<IntervalMap, String> {
+        Ok(self.route(from, to)?.iter().fold(IntervalMap::identity(), |acc, m| acc.then(&m.interval_map())))
+    }
+
+    /// Lowest location of any seed, and that seed.
+    pub fn lowest_location(&self) -> Option<(usize, usize)> {
+        self.seed_map().inverse().lowest(&self.seeds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input2.txt");
+
+    #[test]
+    fn example() {
+        let almanac = Almanac::parse(EXAMPLE, Seeds::Single).unwrap();
+        assert_eq!(almanac.chain().len(), 7);
+        let map = almanac.seed_map();
+        assert_eq!([79, 14, 55, 13].map(|s| map.get(s)), [82, 43, 86, 35]);
+        assert_eq!(almanac.lowest_location(), Some((35, 13)));
+        assert_eq!(map.inverse().get(46), vec![82]);
+
+        let almanac = Almanac::parse(EXAMPLE, Seeds::Ranges).unwrap();
+        assert_eq!(almanac.seeds, vec![(79, 93), (55, 68)]);
+        assert_eq!(almanac.lowest_location(), Some((46, 82)));
+    }
+
+    #[test]
+    fn routes() {
+        let almanac = Almanac::parse(EXAMPLE, Seeds::Single).unwrap();
+        let route = almanac.route("soil", "humidity").unwrap();
+        assert_eq!(route.iter().map(|m| m.to.as_str()).collect::<Vec<_>>(), ["fertilizer", "water", "light", "temperature", "humidity"]);
+        assert!(almanac.route("fertilizer", "fertilizer").unwrap().is_empty());
+        // Seed 79 has soil 81 and humidity 78.
+        assert_eq!(almanac.map_between("soil", "humidity").unwrap().get(81), 78);
+        assert_eq!(almanac.map_between("seed", "location").unwrap(), almanac.seed_map());
+        assert_eq!(almanac.route("location", "seed").unwrap_err(), "no way from location to seed");
+        assert_eq!(almanac.route("moon", "seed").unwrap_err(), "no category moon");
+        assert_eq!(almanac.categories().len(), 8);
+    }
+
+    #[test]
+    fn odd_seed_ranges() {
+        let err = Almanac::parse("seeds: 1 2 3\n\nseed-to-soil map:\n1 2 3\n", Seeds::Ranges).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+        assert!(Almanac::parse("seeds: 1 2 3", Seeds::Single).is_ok());
+    }
+
+    #[test]
+    fn no_seeds() {
+        let err = Almanac::parse("seeds:\n\nseed-to-soil map:\n1 2 3\n", Seeds::Single).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 7, "at least one seed"));
+        // Ranges of no seeds don't count either.
+        assert!(Almanac::parse("seeds: 5 0", Seeds::Ranges).is_err());
+        assert!(crate::part1::solve("seeds: \n\nseed-to-soil map:\n1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn huge_numbers() {
+        let err = Almanac::parse(&format!("seeds: 1 {}", usize::MAX), Seeds::Single).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (10, "smaller number"));
+        let err = Almanac::parse(&format!("seeds: 1 2 3 {}", usize::MAX - 2), Seeds::Ranges).unwrap_err();
+        assert_eq!(err.column, 14);
+        let m = Mapping::new(&format!("seed-to-soil map:\n{} 0 10", usize::MAX - 2)).unwrap();
+        assert_eq!([1, 2, usize::MAX].map(|x| m.get(x)), [usize::MAX - 1, 2, usize::MAX]);
+    }
+
+    #[test]
+    fn overlapping_entries() {
+        let m = Mapping::new("seed-to-soil map:\n200 15 10\n100 10 10\n300 10 2").unwrap();
+        let map = m.interval_map();
+        for x in [10, 11, 12, 15, 19, 20, 24] {
+            assert_eq!(m.get(x), map.get(x), "{}", x);
+        }
+        assert_eq!([10, 15, 20].map(|x| m.get(x)), [100, 105, 205]);
+    }
+}
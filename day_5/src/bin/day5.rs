@@ -0,0 +1,121 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use day_5::almanac::{Almanac, Seeds};
+use day_5::brute::{run_in_terminal, Options};
+use day_5::validate::validate;
+
+/// Checks and queries on an almanac
+#[derive(Parser)]
+struct Cli {
+    /// Almanac to read
+    #[arg(short, long, default_value = "input.txt")]
+    input: String,
+    /// Read the seeds as start/length pairs
+    #[arg(short, long)]
+    ranges: bool,
+    #[command(subcommand)]
+    query: Query,
+}
+
+#[derive(Subcommand)]
+enum Query {
+    /// Overlapping entries, gaps, unreachable categories and cycles
+    Check {
+        /// Category the seeds should end up in
+        #[arg(short, long, default_value = "location")]
+        target: String,
+    },
+    /// Maps that lead from one category to another
+    Route { from: String, to: String },
+    /// Values of one category in another
+    Map { from: String, to: String, values: Vec<usize> },
+    /// Lowest location of any seed
+    Lowest,
+    /// Lowest location by trying every seed, Ctrl-C stops
+    Brute {
+        /// Worker threads, the number of CPUs by default
+        #[arg(short, long)]
+        threads: Option<usize>,
+        /// Give up after this many seconds
+        #[arg(long, value_parser = seconds)]
+        time_limit: Option<Duration>,
+    },
+}
+
+fn seconds(s: &str) -> Result<Duration, String> {
+    let secs = s.parse::<f64>().map_err(|e| e.to_string())?;
+    if secs <= 0.0 {
+        return Err("must be more than 0".to_owned());
+    }
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let cli = Cli::parse();
+    let text = match std::fs::read_to_string(&cli.input) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: {}: {}", cli.input, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let almanac = match Almanac::parse(&text, if cli.ranges { Seeds::Ranges } else { Seeds::Single }) {
+        Ok(almanac) => almanac,
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(&cli.input));
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match cli.query {
+        Query::Check { target } => {
+            let issues = validate(&almanac, &target);
+            for issue in &issues {
+                println!("{}: {}", if issue.is_error() { "error" } else { "note" }, issue);
+            }
+            let errors = issues.iter().filter(|i| i.is_error()).count();
+            println!("{} errors, {} notes", errors, issues.len() - errors);
+            if errors > 0 {
+                return ExitCode::FAILURE;
+            }
+            Ok(())
+        }
+        Query::Route { from, to } => almanac.route(&from, &to).map(|route| {
+            let names = route.iter().map(|m| m.to.as_str());
+            println!("{}", std::iter::once(from.as_str()).chain(names).collect::<Vec<_>>().join(" -> "));
+        }),
+        Query::Map { from, to, values } => almanac.map_between(&from, &to).map(|map| {
+            for value in values {
+                println!("{} {} -> {} {}", from, value, to, map.get(value));
+            }
+        }),
+        Query::Lowest => match almanac.lowest_location() {
+            Some((location, seed)) => {
+                println!("Lowest location {} for seed {}", location, seed);
+                Ok(())
+            }
+            None => Err("no seeds".to_owned()),
+        },
+        Query::Brute { threads, time_limit } => {
+            let mut options = Options::default();
+            options.threads = threads.unwrap_or(options.threads);
+            options.time_limit = time_limit;
+            let outcome = run_in_terminal(&almanac, &options);
+            match outcome.progress.best {
+                Some(best) if outcome.complete => println!("Lowest location {}", best),
+                Some(best) => println!("Lowest location {} of the {} seeds checked before stopping", best, outcome.progress.checked),
+                None => println!("No seeds checked"),
+            }
+            Ok(())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Every seed through every map, one at a time. Far too slow next to
+//! `part2_2`, but it shares none of its code, so it's what that gets checked
+//! against.
+//!
+//! Seed ranges are cut into chunks that a fixed number of worker threads
+//! take turns on. Workers only share a running minimum and a count, the
+//! calling thread reports progress and tells them to stop when cancelled or
+//! out of time.
+
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::almanac::{Almanac, Mapping};
+
+/// How often progress gets reported.
+const TICK: Duration = Duration::from_millis(200);
+/// Seeds between checks whether to stop.
+const STOP_CHECK: usize = 1 << 16;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub threads: usize,
+    /// Seeds per chunk of work.
+    pub chunk: usize,
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { threads: thread::available_parallelism().map_or(1, |n| n.get()), chunk: 1 << 20, time_limit: None }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub checked: u64,
+    pub total: u64,
+    /// Lowest location so far.
+    pub best: Option<usize>,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let done = if self.total == 0 { 1.0 } else { self.checked as f64 / self.total as f64 };
+        write!(f, "{:5.1}% of {} seeds", done * 100.0, self.total)?;
+        if let Some(best) = self.best {
+            write!(f, ", lowest location {}", best)?;
+        }
+        write!(f, ", {:.0}s", self.elapsed.as_secs_f64())?;
+        if done > 0.0 && done < 1.0 {
+            write!(f, ", about {:.0}s left", self.elapsed.as_secs_f64() * (1.0 - done) / done)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub progress: Progress,
+    /// False if stopped before every seed was checked, `best` is then only
+    /// the lowest of those that were.
+    pub complete: bool,
+}
+
+/// Tells the calling thread a worker is done, even if it panicked, and
+/// stops the others when it did.
+struct Done<'a> {
+    done: mpsc::Sender<()>,
+    stop: &'a AtomicBool,
+}
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.stop.store(true, Ordering::Relaxed);
+        }
+        let _ = self.done.send(());
+    }
+}
+
+fn location(chain: &[&Mapping], seed: usize) -> usize {
+    chain.iter().fold(seed, |x, m| m.get(x))
+}
+
+/// Lowest location of all the almanac's seeds. `cancel` stops it early, as
+/// does running out of `options.time_limit`. `report` gets called on the
+/// calling thread every so often and once at the end.
+pub fn brute_force(almanac: &Almanac, options: &Options, cancel: &AtomicBool, mut report: impl FnMut(&Progress)) -> Outcome {
+    let chain = almanac.chain();
+    let chunk = options.chunk.max(1);
+    let chunks = almanac
+        .seeds
+        .iter()
+        .flat_map(|&(start, end)| (start..end).step_by(chunk).map(move |s| (s, end.min(s.saturating_add(chunk)))))
+        .collect::<Vec<_>>();
+    let total = almanac.seeds.iter().map(|(start, end)| (end - start) as u64).sum();
+    let next = AtomicUsize::new(0);
+    let best = AtomicUsize::new(usize::MAX);
+    let checked = AtomicU64::new(0);
+    let stop = AtomicBool::new(cancel.load(Ordering::Relaxed));
+    let started = Instant::now();
+    let progress = || {
+        let best = best.load(Ordering::Relaxed);
+        Progress {
+            checked: checked.load(Ordering::Relaxed),
+            total,
+            best: (best != usize::MAX).then_some(best),
+            elapsed: started.elapsed(),
+        }
+    };
+
+    let threads = options.threads.max(1).min(chunks.len().max(1));
+    thread::scope(|scope| {
+        let (done, finished) = mpsc::channel();
+        for _ in 0..threads {
+            let done = done.clone();
+            let (chain, chunks, next, best, checked, stop) = (&chain, &chunks, &next, &best, &checked, &stop);
+            scope.spawn(move || {
+                let _done = Done { done, stop };
+                while let Some(&(start, end)) = chunks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let mut at = start;
+                    while at < end && !stop.load(Ordering::Relaxed) {
+                        let block_end = end.min(at.saturating_add(STOP_CHECK));
+                        let lowest = (at..block_end).map(|seed| location(chain, seed)).min().unwrap();
+                        best.fetch_min(lowest, Ordering::Relaxed);
+                        checked.fetch_add((block_end - at) as u64, Ordering::Relaxed);
+                        at = block_end;
+                    }
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                }
+            });
+        }
+        let mut running = threads;
+        while running > 0 {
+            match finished.recv_timeout(TICK) {
+                Ok(()) => running -= 1,
+                Err(_) => report(&progress()),
+            }
+            let out_of_time = options.time_limit.is_some_and(|limit| started.elapsed() >= limit);
+            if cancel.load(Ordering::Relaxed) || out_of_time {
+                stop.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let progress = progress();
+    report(&progress);
+    Outcome { progress, complete: progress.checked == total }
+}
+
+/// `brute_force` with Ctrl-C stopping it and progress on one line of stderr.
+pub fn run_in_terminal(almanac: &Almanac, options: &Options) -> Outcome {
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&cancel);
+    if let Err(e) = ctrlc::set_handler(move || flag.store(true, Ordering::Relaxed)) {
+        eprintln!("warning: Ctrl-C won't stop cleanly: {}", e);
+    }
+    let outcome = brute_force(almanac, options, &cancel, |progress| {
+        eprint!("\r\x1b[K{}", progress);
+        std::io::stderr().flush().unwrap();
+    });
+    eprintln!();
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::Seeds;
+
+    fn example() -> Almanac {
+        Almanac::parse(include_str!("../input2.txt"), Seeds::Ranges).unwrap()
+    }
+
+    #[test]
+    fn matches_intervals() {
+        let almanac = example();
+        for threads in [1, 3] {
+            let options = Options { threads, chunk: 5, time_limit: None };
+            let outcome = brute_force(&almanac, &options, &AtomicBool::new(false), |_| {});
+            assert!(outcome.complete);
+            assert_eq!(outcome.progress.checked, 27);
+            assert_eq!(outcome.progress.best, almanac.lowest_location().map(|(location, _)| location));
+        }
+    }
+
+    /// Random seed ranges over a random chain of maps, against the composed
+    /// map.
+    #[test]
+    fn oracle() {
+        let mut state = 11u64;
+        let mut next = |n: usize| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+        for _ in 0..30 {
+            let mut text = String::from("seeds:");
+            for _ in 0..1 + next(3) {
+                text += &format!(" {} {}", next(100), next(50));
+            }
+            for (from, to) in [("seed", "soil"), ("soil", "water"), ("water", "location")] {
+                text += &format!("\n\n{}-to-{} map:", from, to);
+                for _ in 0..next(4) {
+                    text += &format!("\n{} {} {}", next(100), next(100), 1 + next(30));
+                }
+            }
+            let almanac = Almanac::parse(&text, Seeds::Ranges).unwrap();
+            let options = Options { threads: 2, chunk: 7, time_limit: None };
+            let outcome = brute_force(&almanac, &options, &AtomicBool::new(false), |_| {});
+            let expected = almanac.lowest_location().map(|(location, _)| location);
+            assert_eq!(outcome.progress.best, expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn stops() {
+        let almanac = Almanac::parse("seeds: 0 4000000000\n\nseed-to-location map:\n5 0 10", Seeds::Ranges).unwrap();
+        let options = Options { threads: 2, chunk: 1 << 16, time_limit: None };
+        let outcome = brute_force(&almanac, &options, &AtomicBool::new(true), |_| {});
+        assert!(!outcome.complete);
+        assert_eq!(outcome.progress.checked, 0);
+
+        let options = Options { time_limit: Some(Duration::from_millis(300)), ..options };
+        let mut reports = 0;
+        let outcome = brute_force(&almanac, &options, &AtomicBool::new(false), |_| reports += 1);
+        assert!(!outcome.complete);
+        assert!(outcome.progress.checked > 0);
+        assert!(outcome.progress.elapsed < Duration::from_secs(5));
+        assert!(reports >= 2);
+    }
+
+    /// A worker panicking doesn't leave the rest waiting for it.
+    #[test]
+    fn worker_panics() {
+        let stop = AtomicBool::new(false);
+        let (done, finished) = mpsc::channel();
+        let result = thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _done = Done { done, stop: &stop };
+                    panic!("worker failed");
+                })
+                .join()
+        });
+        assert!(result.is_err());
+        assert!(finished.try_recv().is_ok());
+        assert!(stop.load(Ordering::Relaxed));
+    }
+}
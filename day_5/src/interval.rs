@@ -0,0 +1,246 @@
+//! Piecewise maps over `usize` where every piece is a shift, as in the
+//! almanac. They compose, so a whole chain of category maps turns into one,
+//! and reading the pieces in output order goes backwards.
+
+/// `start..end` goes to `dest..dest + (end - start)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub start: usize,
+    pub end: usize,
+    pub dest: usize,
+}
+
+impl Piece {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn get(&self, x: usize) -> usize {
+        self.dest + (x - self.start)
+    }
+}
+
+/// Sorted pieces covering `0..usize::MAX` without gaps. Values no almanac
+/// range covers get an identity piece. No half open piece reaches
+/// `usize::MAX` itself, so where that goes is kept on the side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap {
+    pieces: Vec<Piece>,
+    max: usize,
+}
+
+impl IntervalMap {
+    pub fn identity() -> Self {
+        IntervalMap { pieces: vec![Piece { start: 0, end: usize::MAX, dest: 0 }], max: usize::MAX }
+    }
+
+    /// From almanac style `(dest, src, len)` ranges. Where ranges overlap
+    /// the one starting first wins. A range stops short of values it would
+    /// send to `usize::MAX` or past it.
+    pub fn new(ranges: impl IntoIterator<Item = (usize, usize, usize)>) -> Self {
+        let mut ranges = ranges.into_iter().filter(|r| r.2 > 0).collect::<Vec<_>>();
+        ranges.sort_by_key(|&(_, src, _)| src);
+        let max = ranges
+            .iter()
+            .find(|&&(dest, src, len)| usize::MAX - src < len && usize::MAX - src < usize::MAX - dest)
+            .map_or(usize::MAX, |&(dest, src, _)| dest + (usize::MAX - src));
+        let mut pieces = vec![];
+        let mut at = 0;
+        for (dest, src, len) in ranges {
+            let end = src.saturating_add(len.min(usize::MAX - dest));
+            if end <= at {
+                continue;
+            }
+            if src > at {
+                pieces.push(Piece { start: at, end: src, dest: at });
+            }
+            let start = src.max(at);
+            pieces.push(Piece { start, end, dest: dest + (start - src) });
+            at = end;
+        }
+        if at < usize::MAX {
+            pieces.push(Piece { start: at, end: usize::MAX, dest: at });
+        }
+        IntervalMap::from_pieces(pieces, max)
+    }
+
+    /// Joins neighbours where one continues the other's shift.
+    fn from_pieces(pieces: Vec<Piece>, max: usize) -> Self {
+        let mut ret: Vec<Piece> = vec![];
+        for p in pieces.into_iter().filter(|p| !p.is_empty()) {
+            match ret.last_mut() {
+                Some(last) if last.end == p.start && last.dest + last.len() == p.dest => last.end = p.end,
+                _ => ret.push(p),
+            }
+        }
+        IntervalMap { pieces: ret, max }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    fn first_piece_ending_after(&self, x: usize) -> usize {
+        self.pieces.partition_point(|p| p.end <= x)
+    }
+
+    pub fn get(&self, x: usize) -> usize {
+        if x == usize::MAX {
+            return self.max;
+        }
+        self.pieces[self.first_piece_ending_after(x)].get(x)
+    }
+
+    /// Images of `start..end`, split at every piece boundary in between.
+    pub fn map_range(&self, start: usize, end: usize) -> Vec<(usize, usize)> {
+        let mut ret = vec![];
+        for p in &self.pieces[self.first_piece_ending_after(start)..] {
+            let (s, e) = (start.max(p.start), end.min(p.end));
+            if s >= e {
+                break;
+            }
+            ret.push((p.get(s), p.get(s) + (e - s)));
+        }
+        ret
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &IntervalMap) -> IntervalMap {
+        let mut pieces = vec![];
+        for p in &self.pieces {
+            let mut at = p.start;
+            for (s, e) in next.map_range(p.dest, p.dest + p.len()) {
+                pieces.push(Piece { start: at, end: at + (e - s), dest: s });
+                at += e - s;
+            }
+        }
+        IntervalMap::from_pieces(pieces, next.get(self.max))
+    }
+
+    pub fn inverse(&self) -> Inverse {
+        let mut pieces = self.pieces.clone();
+        pieces.sort_by_key(|p| (p.dest, p.start));
+        Inverse { pieces }
+    }
+}
+
+/// The pieces of a map ordered by where they go. Different pieces can land
+/// on the same values, so going back gives any number of inputs.
+#[derive(Debug, Clone)]
+pub struct Inverse {
+    pieces: Vec<Piece>,
+}
+
+impl Inverse {
+    /// Every input mapping to `y`, smallest first.
+    pub fn get(&self, y: usize) -> Vec<usize> {
+        let mut ret = self
+            .pieces
+            .iter()
+            .take_while(|p| p.dest <= y)
+            .filter(|p| y - p.dest < p.len())
+            .map(|p| p.start + (y - p.dest))
+            .collect::<Vec<_>>();
+        ret.sort_unstable();
+        ret
+    }
+
+    /// Smallest output of any input in the half open `ranges`, along with
+    /// that input. Pieces are tried from the lowest output up, stopping once
+    /// none can beat the best found.
+    pub fn lowest(&self, ranges: &[(usize, usize)]) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize)> = None;
+        for p in &self.pieces {
+            if best.is_some_and(|(out, _)| p.dest >= out) {
+                break;
+            }
+            for &(start, end) in ranges {
+                let lo = start.max(p.start);
+                if lo < end.min(p.end) && best.is_none_or(|(out, _)| p.get(lo) < out) {
+                    best = Some((p.get(lo), lo));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed_to_soil() -> IntervalMap {
+        IntervalMap::new([(50, 98, 2), (52, 50, 48)])
+    }
+
+    #[test]
+    fn get() {
+        let map = seed_to_soil();
+        assert_eq!([0, 49, 50, 53, 97, 98, 99, 100].map(|x| map.get(x)), [0, 49, 52, 55, 99, 50, 51, 100]);
+        assert_eq!(map.pieces().len(), 4);
+        assert_eq!(IntervalMap::new([]), IntervalMap::identity());
+        // Shifting by 0 is no piece of its own.
+        assert_eq!(IntervalMap::new([(5, 5, 10)]), IntervalMap::identity());
+    }
+
+    #[test]
+    fn overlaps() {
+        let map = IntervalMap::new([(100, 10, 10), (200, 15, 10)]);
+        assert_eq!([14, 15, 19, 20, 24, 25].map(|x| map.get(x)), [104, 105, 109, 205, 209, 25]);
+    }
+
+    #[test]
+    fn near_usize_max() {
+        let max = usize::MAX;
+        assert_eq!(IntervalMap::identity().get(max), max);
+        let map = IntervalMap::new([(0, max - 5, 10)]);
+        assert_eq!([max - 6, max - 5, max].map(|x| map.get(x)), [max - 6, 0, 5]);
+        // Only the values that land below usize::MAX get shifted.
+        let map = IntervalMap::new([(max - 2, 10, 10)]);
+        assert_eq!([10, 11, 12].map(|x| map.get(x)), [max - 2, max - 1, 12]);
+        let composed = map.then(&IntervalMap::new([(7, max - 10, 20)]));
+        assert_eq!([11, max].map(|x| composed.get(x)), [16, 17]);
+    }
+
+    #[test]
+    fn ranges_across_boundaries() {
+        let map = seed_to_soil();
+        assert_eq!(map.map_range(40, 105), vec![(40, 50), (52, 100), (50, 52), (100, 105)]);
+        assert_eq!(map.map_range(60, 61), vec![(62, 63)]);
+        assert_eq!(map.map_range(60, 60), vec![]);
+    }
+
+    /// Little random maps to check composing and inverting against.
+    fn random_maps(state: &mut u64, count: usize) -> Vec<IntervalMap> {
+        let mut next = |n: usize| {
+            *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (*state >> 33) as usize % n
+        };
+        (0..count).map(|_| IntervalMap::new((0..next(4)).map(|_| (next(60), next(60), next(20))).collect::<Vec<_>>())).collect()
+    }
+
+    #[test]
+    fn composition() {
+        let mut state = 3;
+        for _ in 0..50 {
+            let maps = random_maps(&mut state, 4);
+            let composed = maps.iter().fold(IntervalMap::identity(), |acc, m| acc.then(m));
+            for x in 0..120 {
+                assert_eq!(composed.get(x), maps.iter().fold(x, |x, m| m.get(x)), "{:?}", maps);
+            }
+            let inverse = composed.inverse();
+            for y in 0..120 {
+                let expected = (0..200).filter(|&x| composed.get(x) == y).collect::<Vec<_>>();
+                assert_eq!(inverse.get(y), expected);
+            }
+            for (start, end) in [(0, 1), (7, 30), (25, 90), (0, 200)] {
+                let best = (start..end).map(|x| (composed.get(x), x)).min().unwrap();
+                assert_eq!(inverse.lowest(&[(start, end)]).unwrap().0, best.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod almanac;
+pub mod brute;
+pub mod interval;
+pub mod part1;
+pub mod part2;
+pub mod part2_2;
+pub mod validate;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn solve_part1(input: &str) -> Result<usize, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<usize, ParseError> {
+        part2_2::solve(input)
+    }
+}
@@ -0,0 +1,11 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::almanac::{Almanac, Seeds};
+
+pub fn solve(content: &str) -> Result<usize, ParseError> {
+    let almanac = Almanac::parse(content, Seeds::Single)?;
+    let (location, seed) = almanac.lowest_location().ok_or_else(|| ParseError::new("at least one seed", content.lines().next().unwrap_or_default()).locate(content))?;
+    trace!("Seed {} is at {}", seed, location);
+    Ok(location)
+}
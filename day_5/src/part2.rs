@@ -0,0 +1,14 @@
+use std::sync::atomic::AtomicBool;
+
+use aoc_common::ParseError;
+use tracing::debug;
+
+use crate::almanac::{Almanac, Seeds};
+use crate::brute::{brute_force, Options};
+
+// Brute force over every seed, see `brute`. `part2_2` is the fast one.
+pub fn solve(content: &str) -> Result<usize, ParseError> {
+    let almanac = Almanac::parse(content, Seeds::Ranges)?;
+    let outcome = brute_force(&almanac, &Options::default(), &AtomicBool::new(false), |p| debug!("{}", p));
+    outcome.progress.best.ok_or_else(|| ParseError::new("at least one seed", content.lines().next().unwrap_or_default()).locate(content))
+}
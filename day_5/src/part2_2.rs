@@ -0,0 +1,16 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::almanac::{Almanac, Seeds};
+
+// Whole seed ranges go through one composed map, the lowest location is
+// found by walking its pieces from the lowest location up.
+pub fn solve(content: &str) -> Result<usize, ParseError> {
+    let almanac = Almanac::parse(content, Seeds::Ranges)?;
+    trace!("{:?}", almanac.seeds);
+    let map = almanac.seed_map();
+    trace!("{} pieces from seed to location", map.pieces().len());
+    let (location, seed) = map.inverse().lowest(&almanac.seeds).ok_or_else(|| ParseError::new("at least one seed", content.lines().next().unwrap_or_default()).locate(content))?;
+    trace!("Seed {} is at {}", seed, location);
+    Ok(location)
+}
@@ -0,0 +1,174 @@
+//! Things in an almanac that the solvers would silently get wrong.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::almanac::{Almanac, Mapping};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// Two entries of a map, 1-based, send the same source values
+    /// different places. The one starting first wins.
+    Overlap { map: String, entries: (usize, usize), range: (usize, usize) },
+    /// The same entry twice.
+    Duplicate { map: String, entries: (usize, usize) },
+    /// More than one map out of a category.
+    SameSource { category: String, maps: Vec<String> },
+    /// Source values between entries, which map to themselves.
+    Gap { map: String, range: (usize, usize) },
+    Unreachable { category: String },
+    Cycle { categories: Vec<String> },
+    NoRoute { from: String, to: String },
+}
+
+impl Issue {
+    /// Gaps are fine by the puzzle's rules, everything else isn't.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Issue::Gap { .. })
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::Overlap { map, entries, range } => {
+                write!(f, "{} map: entries {} and {} overlap on {}..{}", map, entries.0, entries.1, range.0, range.1)
+            }
+            Issue::Duplicate { map, entries } => write!(f, "{} map: entry {} repeats entry {}", map, entries.1, entries.0),
+            Issue::SameSource { category, maps } => write!(f, "{} has more than one map: {}", category, maps.join(", ")),
+            Issue::Gap { map, range } => write!(f, "{} map: nothing covers {}..{}", map, range.0, range.1),
+            Issue::Unreachable { category } => write!(f, "{} can't be reached from seed", category),
+            Issue::Cycle { categories } => write!(f, "cycle: {} -> {}", categories.join(" -> "), categories[0]),
+            Issue::NoRoute { from, to } => write!(f, "no way from {} to {}", from, to),
+        }
+    }
+}
+
+fn name(m: &Mapping) -> String {
+    format!("{}-to-{}", m.from, m.to)
+}
+
+fn check_entries(m: &Mapping, issues: &mut Vec<Issue>) {
+    let end = |r: &(usize, usize, usize)| r.1.saturating_add(r.2);
+    for (i, a) in m.ranges.iter().enumerate() {
+        for (j, b) in m.ranges.iter().enumerate().skip(i + 1) {
+            if a == b {
+                issues.push(Issue::Duplicate { map: name(m), entries: (i + 1, j + 1) });
+            } else if a.1.max(b.1) < end(a).min(end(b)) {
+                issues.push(Issue::Overlap { map: name(m), entries: (i + 1, j + 1), range: (a.1.max(b.1), end(a).min(end(b))) });
+            }
+        }
+    }
+    let mut ranges = m.ranges.iter().filter(|r| r.2 > 0).collect::<Vec<_>>();
+    ranges.sort_by_key(|r| r.1);
+    let mut covered = ranges.first().map_or(0, |r| end(r));
+    for r in ranges.iter().skip(1) {
+        if r.1 > covered {
+            issues.push(Issue::Gap { map: name(m), range: (covered, r.1) });
+        }
+        covered = covered.max(end(r));
+    }
+}
+
+/// Cycles in the category graph, each once, starting at its first category
+/// in `categories` order.
+fn cycles(almanac: &Almanac, categories: &[&str]) -> Vec<Vec<String>> {
+    fn visit<'a>(almanac: &'a Almanac, cat: &'a str, path: &mut Vec<&'a str>, done: &mut HashSet<&'a str>, found: &mut Vec<Vec<&'a str>>) {
+        if let Some(pos) = path.iter().position(|&c| c == cat) {
+            found.push(path[pos..].to_vec());
+            return;
+        }
+        if done.contains(cat) {
+            return;
+        }
+        path.push(cat);
+        for m in almanac.mappings.iter().filter(|m| m.from == cat) {
+            visit(almanac, &m.to, path, done, found);
+        }
+        path.pop();
+        done.insert(cat);
+    }
+
+    let mut found = vec![];
+    let mut done = HashSet::new();
+    for cat in categories {
+        visit(almanac, cat, &mut vec![], &mut done, &mut found);
+    }
+    let order = |c: &str| categories.iter().position(|&x| x == c);
+    let mut ret = found
+        .into_iter()
+        .map(|mut cycle| {
+            let first = (0..cycle.len()).min_by_key(|&i| order(cycle[i])).unwrap();
+            cycle.rotate_left(first);
+            cycle.into_iter().map(|c| c.to_owned()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    ret.dedup();
+    ret
+}
+
+/// Everything wrong with `almanac`, given the category the answer is in.
+pub fn validate(almanac: &Almanac, target: &str) -> Vec<Issue> {
+    let mut issues = vec![];
+    for m in &almanac.mappings {
+        check_entries(m, &mut issues);
+    }
+    let categories = almanac.categories();
+    for cat in &categories {
+        let maps = almanac.mappings.iter().filter(|m| m.from == *cat).map(name).collect::<Vec<_>>();
+        if maps.len() > 1 {
+            issues.push(Issue::SameSource { category: cat.to_string(), maps });
+        }
+    }
+    for cat in categories.iter().filter(|&&c| c != "seed") {
+        if almanac.route("seed", cat).is_err() {
+            issues.push(Issue::Unreachable { category: cat.to_string() });
+        }
+    }
+    for categories in cycles(almanac, &categories) {
+        issues.push(Issue::Cycle { categories });
+    }
+    if almanac.route("seed", target).is_err() {
+        issues.push(Issue::NoRoute { from: "seed".to_owned(), to: target.to_owned() });
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::Seeds;
+
+    #[test]
+    fn example_is_fine() {
+        let almanac = Almanac::parse(include_str!("../input2.txt"), Seeds::Single).unwrap();
+        assert_eq!(validate(&almanac, "location"), vec![]);
+    }
+
+    #[test]
+    fn broken() {
+        let almanac = Almanac::parse(
+            "seeds: 1\n\nseed-to-soil map:\n10 0 5\n20 3 5\n10 0 5\n0 20 2\n\nsoil-to-water map:\n1 1 1\n\n\
+             water-to-soil map:\n1 1 1\n\nsoil-to-air map:\n1 1 1\n\nmoon-to-sun map:\n1 1 1\n",
+            Seeds::Single,
+        )
+        .unwrap();
+        let issues = validate(&almanac, "location").iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            issues,
+            vec![
+                "seed-to-soil map: entries 1 and 2 overlap on 3..5",
+                "seed-to-soil map: entry 3 repeats entry 1",
+                "seed-to-soil map: entries 2 and 3 overlap on 3..5",
+                "seed-to-soil map: nothing covers 8..20",
+                "soil has more than one map: soil-to-water, soil-to-air",
+                "moon can't be reached from seed",
+                "sun can't be reached from seed",
+                "cycle: soil -> water -> soil",
+                "no way from seed to location",
+            ]
+        );
+        let gap = validate(&almanac, "air").into_iter().filter(|i| !i.is_error()).collect::<Vec<_>>();
+        assert_eq!(gap, vec![Issue::Gap { map: "seed-to-soil".to_owned(), range: (8, 20) }]);
+    }
+}
@@ -0,0 +1,75 @@
+//! `race` for times and records of any size.
+
+use num_bigint::BigUint;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigRace {
+    pub time: BigUint,
+    pub record: BigUint,
+}
+
+impl BigRace {
+    /// From decimal digits.
+    pub fn parse(time: &str, record: &str) -> Option<Self> {
+        Some(BigRace { time: time.parse().ok()?, record: record.parse().ok()? })
+    }
+
+    pub fn beats(&self, hold: &BigUint) -> bool {
+        hold <= &self.time && hold * (&self.time - hold) > self.record
+    }
+
+    /// Same as `Race::winning_holds`.
+    pub fn winning_holds(&self) -> Option<(BigUint, BigUint)> {
+        let square = &self.time * &self.time;
+        let four_record = &self.record * 4u32;
+        if four_record >= square {
+            return None;
+        }
+        let half = &self.time / 2u32;
+        let mut lo = (&self.time - (square - four_record).sqrt()) / 2u32;
+        while lo <= half && !self.beats(&lo) {
+            lo += 1u32;
+        }
+        while lo > BigUint::ZERO && self.beats(&(&lo - 1u32)) {
+            lo -= 1u32;
+        }
+        (lo <= half).then(|| (lo.clone(), &self.time - lo))
+    }
+
+    pub fn ways_to_win(&self) -> BigUint {
+        self.winning_holds().map_or(BigUint::ZERO, |(lo, hi)| hi - lo + 1u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::race::Race;
+
+    #[test]
+    fn matches_u128() {
+        for time in 0..40u64 {
+            for record in 0..(time as u128 * time as u128 / 4 + 2) {
+                let big = BigRace { time: time.into(), record: record.into() };
+                assert_eq!(big.ways_to_win(), Race { time, record }.ways_to_win().into());
+            }
+        }
+    }
+
+    #[test]
+    fn huge() {
+        let race = BigRace::parse("1000000000000000000000000000000000000000000000000000000000001", "7").unwrap();
+        let (lo, hi) = race.winning_holds().unwrap();
+        assert_eq!(lo, 1u32.into());
+        assert_eq!(&hi + 1u32, race.time);
+
+        let time: BigUint = "123456789012345678901234567890123456789".parse().unwrap();
+        let half = &time / 2u32;
+        let best = &half * (&time - &half);
+        let race = BigRace { time, record: &best - 12345u32 };
+        let (lo, hi) = race.winning_holds().unwrap();
+        assert!(race.beats(&lo) && !race.beats(&(&lo - 1u32)));
+        assert!(race.beats(&hi) && !race.beats(&(&hi + 1u32)));
+        assert!(BigRace { record: best, ..race }.winning_holds().is_none());
+    }
+}
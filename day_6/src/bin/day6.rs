@@ -0,0 +1,66 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_6::race::{parse_columns, Race};
+
+/// Which hold times win each boat race
+#[derive(Parser)]
+struct Cli {
+    /// Race sheet to read
+    #[arg(short, long, default_value = "input.txt")]
+    input: String,
+    /// Read each line as one number, like part 2
+    #[arg(short, long)]
+    joined: bool,
+}
+
+fn describe(time: &str, record: &str) -> Result<String, String> {
+    if let (Ok(time), Ok(record)) = (time.parse(), record.parse()) {
+        let race = Race { time, record };
+        return Ok(match race.winning_holds() {
+            Some((lo, hi)) => format!("hold {} to {} ms, {} ways to win", lo, hi, race.ways_to_win()),
+            None => "no way to win".to_owned(),
+        });
+    }
+    #[cfg(feature = "bigint")]
+    {
+        let race = day_6::big::BigRace::parse(time, record).ok_or("not a number")?;
+        Ok(match race.winning_holds() {
+            Some((lo, hi)) => format!("hold {} to {} ms, {} ways to win", lo, hi, race.ways_to_win()),
+            None => "no way to win".to_owned(),
+        })
+    }
+    #[cfg(not(feature = "bigint"))]
+    Err("too big, build with --features bigint".to_owned())
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let cli = Cli::parse();
+    let text = match std::fs::read_to_string(&cli.input) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: {}: {}", cli.input, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (times, distances) = match parse_columns(&text) {
+        Ok((times, distances)) if cli.joined => (vec![times.concat()], vec![distances.concat()]),
+        Ok((times, distances)) => (times.iter().map(|t| t.to_string()).collect(), distances.iter().map(|d| d.to_string()).collect()),
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(&cli.input));
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    for (time, record) in times.iter().zip(&distances) {
+        match describe(time, record) {
+            Ok(text) => println!("Time {}, record {}: {}", time, record, text),
+            Err(e) => {
+                println!("Time {}, record {}: {}", time, record, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
@@ -0,0 +1,22 @@
+use aoc_common::{ParseError, Solution};
+
+#[cfg(feature = "bigint")]
+pub mod big;
+pub mod part1;
+pub mod part2;
+pub mod race;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn solve_part1(input: &str) -> Result<u128, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<u128, ParseError> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::race::{parse_columns, races};
+
+pub fn solve(contents: &str) -> Result<u128, ParseError> {
+    let races = races(contents)?;
+    let (times, _) = parse_columns(contents)?;
+    let mut product = 1u128;
+    for (race, time) in races.iter().zip(times) {
+        trace!("{:?} wins holding {:?}", race, race.winning_holds());
+        product = product
+            .checked_mul(race.ways_to_win())
+            .ok_or_else(|| ParseError::new("races whose ways to win multiply to below 2^128", time).locate(contents))?;
+    }
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_product_overflows() {
+        let max = u64::MAX;
+        let input = format!("Time: {} {} {}\nDistance: 0 0 0\n", max, max, max);
+        let err = solve(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 49));
+        assert_eq!(solve(&format!("Time: {} 7\nDistance: 0 9\n", max)), Ok((max as u128 - 1) * 4));
+    }
+}
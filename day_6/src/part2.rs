@@ -0,0 +1,10 @@
+use aoc_common::ParseError;
+use tracing::trace;
+
+use crate::race::joined_race;
+
+pub fn solve(contents: &str) -> Result<u128, ParseError> {
+    let race = joined_race(contents)?;
+    trace!("{:?} wins holding {:?}", race, race.winning_holds());
+    Ok(race.ways_to_win())
+}
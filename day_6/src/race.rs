@@ -0,0 +1,176 @@
+//! Holding the button for `h` of `time` ms goes `h * (time - h)` mm, which
+//! beats the record between the roots of `h² - time·h + record = 0`. Those
+//! come from an integer square root and get nudged onto the first and last
+//! hold times that really beat the record, so a tie never counts.
+
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Scanner};
+
+/// Times fit in 64 bits so that `time²` fits in 128.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record: u128,
+}
+
+impl Race {
+    pub fn distance(&self, hold: u64) -> u128 {
+        hold as u128 * (self.time - hold) as u128
+    }
+
+    pub fn beats(&self, hold: u64) -> bool {
+        self.distance(hold) > self.record
+    }
+
+    /// First and last hold time beating the record, `None` if none does.
+    pub fn winning_holds(&self) -> Option<(u64, u64)> {
+        let time = self.time as u128;
+        let discriminant = time * time - self.record.checked_mul(4).filter(|&r| r < time * time)?;
+        let half = self.time / 2;
+        let mut lo = ((time - discriminant.isqrt()) / 2) as u64;
+        while lo <= half && !self.beats(lo) {
+            lo += 1;
+        }
+        while lo > 0 && self.beats(lo - 1) {
+            lo -= 1;
+        }
+        // Distances are symmetric around half the time.
+        (lo <= half).then(|| (lo, self.time - lo))
+    }
+
+    pub fn ways_to_win(&self) -> u128 {
+        self.winning_holds().map_or(0, |(lo, hi)| (hi - lo) as u128 + 1)
+    }
+}
+
+/// The numbers after `label`, as written.
+fn numbers<'a>(line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mut s = Scanner::new(line);
+    s.tag(label)?;
+    s.tag(":")?;
+    let mut ret = vec![];
+    loop {
+        s.spaces();
+        if s.is_empty() {
+            return Ok(ret);
+        }
+        ret.push(s.take_while1("a number", |c| c.is_ascii_digit())?);
+    }
+}
+
+/// Times and distances, still as text so that they can be joined up.
+pub fn parse_columns(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let mut lines = input.lines();
+    let (time_line, distance_line) = (lines.next().unwrap_or_default(), lines.next().unwrap_or_default());
+    let times = numbers(time_line, "Time").map_err(|e| e.locate(input))?;
+    let distances = numbers(distance_line, "Distance").map_err(|e| e.locate(input))?;
+    if times.len() != distances.len() {
+        let err = ParseError::new(format!("{} distances", times.len()), &distance_line[distance_line.len()..]);
+        return Err(err.locate(input));
+    }
+    Ok((times, distances))
+}
+
+fn race(time: &str, record: &str) -> Result<Race, ParseError> {
+    Ok(Race {
+        time: time.parse().map_err(|_| ParseError::new("a time below 2^64", time))?,
+        record: record.parse().map_err(|_| ParseError::new("a distance below 2^128", record))?,
+    })
+}
+
+/// Every column is a race.
+pub fn races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = parse_columns(input)?;
+    times.iter().zip(&distances).map(|(t, d)| race(t, d)).collect::<Result<_, _>>().map_err(|e| e.locate(input))
+}
+
+/// `tokens` written one after the other as one number. Fails at the token
+/// that makes it too big.
+fn joined<T: FromStr>(tokens: &[&str], expected: &str) -> Result<T, ParseError> {
+    let mut digits = String::new();
+    for token in tokens {
+        digits += token;
+        if digits.parse::<T>().is_err() {
+            return Err(ParseError::new(expected, token));
+        }
+    }
+    digits.parse().map_err(|_| ParseError::new(expected, ""))
+}
+
+/// All the digits of each line make one race.
+pub fn joined_race(input: &str) -> Result<Race, ParseError> {
+    let (times, distances) = parse_columns(input)?;
+    if times.is_empty() {
+        return Err(ParseError::end_of_input("a race", input));
+    }
+    let time = joined(&times, "a time below 2^64").map_err(|e| e.locate(input))?;
+    let record = joined(&distances, "a distance below 2^128").map_err(|e| e.locate(input))?;
+    Ok(Race { time, record })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(race: &Race) -> u128 {
+        (0..=race.time).filter(|&h| race.beats(h)).count() as u128
+    }
+
+    #[test]
+    fn example() {
+        let input = include_str!("../input2.txt");
+        let races = races(input).unwrap();
+        assert_eq!(races.iter().map(|r| r.winning_holds()).collect::<Vec<_>>(), vec![Some((2, 5)), Some((4, 11)), Some((11, 19))]);
+        let race = joined_race(input).unwrap();
+        assert_eq!(race, Race { time: 71530, record: 940200 });
+        assert_eq!(race.ways_to_win(), 71503);
+    }
+
+    #[test]
+    fn boundaries() {
+        // 5 * 5 ties the record, a perfect square discriminant.
+        assert_eq!(Race { time: 10, record: 25 }.winning_holds(), None);
+        assert_eq!(Race { time: 10, record: 24 }.winning_holds(), Some((5, 5)));
+        assert_eq!(Race { time: 10, record: 21 }.winning_holds(), Some((4, 6)));
+        // Odd times peak at two holds, 1 * 2 here.
+        assert_eq!(Race { time: 3, record: 2 }.winning_holds(), None);
+        assert_eq!(Race { time: 3, record: 1 }.winning_holds(), Some((1, 2)));
+        assert_eq!(Race { time: 0, record: 0 }.winning_holds(), None);
+        assert_eq!(Race { time: 1, record: 0 }.winning_holds(), None);
+        assert_eq!(Race { time: 2, record: 0 }.winning_holds(), Some((1, 1)));
+        assert_eq!(Race { time: 5, record: u128::MAX }.winning_holds(), None);
+        for time in 0..60 {
+            for record in 0..(time as u128 * time as u128 / 4 + 3) {
+                let race = Race { time, record };
+                assert_eq!(race.ways_to_win(), brute_force(&race), "{:?}", race);
+            }
+        }
+    }
+
+    #[test]
+    fn beyond_f64() {
+        // Hold times around 2^62, where f64 can't tell neighbours apart.
+        let time = u64::MAX - 6;
+        let best = (time / 2) as u128 * (time - time / 2) as u128;
+        for record in [best - 1, best - 1_000_000_007, best / 3 * 2] {
+            let race = Race { time, record };
+            let (lo, hi) = race.winning_holds().unwrap();
+            assert!(race.beats(lo) && !race.beats(lo - 1), "{:?}", race);
+            assert!(race.beats(hi) && !race.beats(hi + 1), "{:?}", race);
+        }
+        assert_eq!(Race { time, record: best }.winning_holds(), None);
+    }
+
+    #[test]
+    fn bad_input() {
+        let err = races("Time: 1 2\nDistance: 3\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 12, "2 distances"));
+        let err = joined_race("Time: 99999999999 99999999999\nDistance: 1 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 19));
+        let err = joined_race(&format!("Time: 1 2 3\nDistance: 1 {} 3\n", u128::MAX)).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 13, "a distance below 2^128"));
+        assert_eq!(joined_race("Time:\nDistance:\n").unwrap_err().expected, "a race");
+        assert!(races("Time: 1 x\nDistance: 3 4\n").is_err());
+    }
+}
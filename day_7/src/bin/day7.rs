@@ -0,0 +1,144 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use day_7::hand::{ranked, Hand};
+use day_7::odds::{kind_odds, possible_hands, standing, KindOdds, Method};
+use day_7::rules::Rules;
+
+/// Camel Cards under any rule set
+#[derive(Parser)]
+struct Cli {
+    /// Rule file, see rules/part1.toml
+    #[arg(short, long, default_value = "rules/part1.toml")]
+    rules: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Total winnings of a list of hands and bids
+    Winnings {
+        /// Hands to read
+        #[arg(short, long, default_value = "input.txt")]
+        input: String,
+        /// Print every hand with its kind and rank
+        #[arg(short, long)]
+        list: bool,
+    },
+    /// Chance of each kind of hand, with and without the wild cards
+    Kinds {
+        /// Sample this many random hands instead of looking at every one
+        #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+        samples: Option<u64>,
+    },
+    /// How a hand does against random hands
+    Rank {
+        hand: String,
+        /// Random hands it's up against
+        #[arg(short, long, default_value_t = 1)]
+        opponents: u64,
+        /// Sample this many random hands instead of looking at every one
+        #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+        samples: Option<u64>,
+    },
+}
+
+/// How many hands were looked at, and how.
+fn describe(method: Method, hands: u64) -> String {
+    match method {
+        Method::Exact => format!("every one of {} hands", hands),
+        Method::Sample { .. } => format!("{} random hands", hands),
+    }
+}
+
+fn chance(odds: &KindOdds, kind: usize) -> String {
+    if odds.exact {
+        format!("{}/{} ({:.4}%)", odds.counts[kind], odds.hands, odds.probability(kind) * 100.0)
+    } else {
+        format!("{:.4}%", odds.probability(kind) * 100.0)
+    }
+}
+
+fn main() -> ExitCode {
+    aoc_common::init_tracing(0);
+    let cli = Cli::parse();
+    let rules = match Rules::load(&cli.rules) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match cli.command {
+        Command::Winnings { input, list } => {
+            let text = match std::fs::read_to_string(&input) {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("error: {}: {}", input, e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let hands = match ranked(&rules, &text) {
+                Ok(hands) => hands,
+                Err(e) => {
+                    eprintln!("error: {}", e.with_file(&input));
+                    return ExitCode::FAILURE;
+                }
+            };
+            if list {
+                for (idx, (hand, bid)) in hands.iter().enumerate() {
+                    println!("{:>5} {} {:<12} {}", idx + 1, String::from_iter(&hand.cards), hand.kind_name(&rules), bid);
+                }
+            }
+            println!("Total winnings: {}", hands.iter().enumerate().map(|(idx, (_, bid))| (idx as u64 + 1) * bid).sum::<u64>());
+        }
+        Command::Kinds { samples } => {
+            let method = match Method::choose(&rules, samples) {
+                Ok(method) => method,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let odds = kind_odds(&rules, method);
+            let plain = (!rules.wild.is_empty()).then(|| kind_odds(&rules.without_wild(), method));
+            let width = rules.kinds.iter().map(|k| k.name.len()).max().unwrap();
+            match possible_hands(&rules) {
+                Some(n) => println!("{} possible hands, from {}", n, describe(method, odds.hands)),
+                None => println!("More possible hands than fit in 64 bits, from {}", describe(method, odds.hands)),
+            }
+            match &plain {
+                Some(_) => println!("{:width$}  {:<32}  With {} wild", "", "Without wild cards", String::from_iter(&rules.wild)),
+                None => println!("No wild cards"),
+            }
+            for (kind, k) in rules.kinds.iter().enumerate().rev() {
+                match &plain {
+                    Some(plain) => println!("{:width$}  {:<32}  {}", k.name, chance(plain, kind), chance(&odds, kind)),
+                    None => println!("{:width$}  {}", k.name, chance(&odds, kind)),
+                }
+            }
+        }
+        Command::Rank { hand, opponents, samples } => {
+            let hand = match Hand::new(&rules, &hand) {
+                Ok(h) => h,
+                Err(e) => {
+                    eprintln!("error: {}", e.locate(&hand));
+                    return ExitCode::FAILURE;
+                }
+            };
+            let method = match Method::choose(&rules, samples) {
+                Ok(method) => method,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let standing = standing(&rules, &hand, method);
+            println!("{} is {}, against {}", String::from_iter(&hand.cards), hand.kind_name(&rules), describe(method, standing.hands));
+            println!("Beats {:.4}% of hands, ties {:.4}%", standing.win_chance() * 100.0, standing.tied as f64 / standing.hands as f64 * 100.0);
+            println!("Expected rank among {} hands: {:.4} (1 is the weakest)", opponents + 1, standing.expected_rank(opponents));
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,107 @@
+use aoc_common::{ParseError, Scanner};
+use tracing::trace;
+
+use crate::rules::Rules;
+
+/// Hands compare by kind, then card by card.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    /// Index into the rules' kinds.
+    pub kind: usize,
+    /// Rank of each card, as dealt.
+    pub ranks: Vec<usize>,
+    pub cards: Vec<char>,
+}
+
+impl Hand {
+    pub fn new(rules: &Rules, s: &str) -> Result<Self, ParseError> {
+        if s.chars().count() != rules.hand_size {
+            return Err(ParseError::new(format!("{} cards", rules.hand_size), s));
+        }
+        let ranks = s
+            .char_indices()
+            .map(|(i, c)| rules.rank(c).ok_or_else(|| ParseError::new(format!("one of {}", rules.order_string()), &s[i..i + c.len_utf8()])))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Hand::from_ranks(rules, ranks))
+    }
+
+    /// From ranks rather than text.
+    pub fn from_ranks(rules: &Rules, ranks: Vec<usize>) -> Self {
+        let mut counts = vec![0; rules.order.len()];
+        let mut wild = 0;
+        for &rank in &ranks {
+            if rules.is_wild(rules.order[rank]) {
+                wild += 1;
+            } else {
+                counts[rank] += 1;
+            }
+        }
+        let cards = ranks.iter().map(|&r| rules.order[r]).collect();
+        Hand { kind: rules.classify(&counts, wild), ranks, cards }
+    }
+
+    pub fn kind_name<'a>(&self, rules: &'a Rules) -> &'a str {
+        &rules.kinds[self.kind].name
+    }
+}
+
+// 32T3K 765
+pub fn parse_hands(rules: &Rules, input: &str) -> Result<Vec<(Hand, u64)>, ParseError> {
+    let parse = |line: &str| -> Result<(Hand, u64), ParseError> {
+        let mut s = Scanner::new(line);
+        let hand = Hand::new(rules, s.take_while1("a hand", |c| !c.is_whitespace())?)?;
+        s.spaces();
+        let bid = s.unsigned()?;
+        s.end()?;
+        Ok((hand, bid))
+    };
+    input.split('\n').filter(|l| !l.is_empty()).map(parse).collect::<Result<Vec<_>, _>>().map_err(|e| e.locate(input))
+}
+
+/// Hands weakest first, each with its bid.
+pub fn ranked(rules: &Rules, input: &str) -> Result<Vec<(Hand, u64)>, ParseError> {
+    let mut hands = parse_hands(rules, input)?;
+    hands.sort();
+    for (idx, (hand, bid)) in hands.iter().enumerate() {
+        trace!("{} {} {}*{}", hand.kind_name(rules), String::from_iter(&hand.cards), bid, idx + 1);
+    }
+    Ok(hands)
+}
+
+/// Every bid times its hand's rank, added up.
+pub fn winnings(rules: &Rules, input: &str) -> Result<u64, ParseError> {
+    Ok(ranked(rules, input)?.iter().enumerate().map(|(idx, (_, bid))| (idx as u64 + 1) * bid).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_hands() {
+        let rules = Rules::part1();
+        let err = parse_hands(&rules, "32T3K 765\nKK677 28\nKTJJ 220\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 1, "5 cards"));
+        let err = parse_hands(&rules, "32T3K 765\nKK6X7 28\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 4, "one of 23456789TJQKA"));
+        assert!(parse_hands(&rules, "32T3K\n").is_err());
+        assert!(parse_hands(&rules, "32T3K 1 2\n").is_err());
+    }
+
+    #[test]
+    fn from_ranks() {
+        let rules = Rules::part2();
+        let hand = Hand::new(&rules, "T55J5").unwrap();
+        assert_eq!(Hand::from_ranks(&rules, hand.ranks.clone()), hand);
+    }
+
+    /// Same winnings whatever order the hands come in.
+    #[test]
+    fn example() {
+        let input = include_str!("../input2.txt");
+        let mut lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(winnings(&Rules::part1(), input).unwrap(), 6440);
+        lines.reverse();
+        assert_eq!(winnings(&Rules::part2(), &lines.join("\n")).unwrap(), 5905);
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod hand;
+pub mod odds;
+pub mod part1;
+pub mod part2;
+pub mod rules;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn solve_part1(input: &str) -> Result<u64, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<u64, ParseError> {
+        part2::solve(input)
+    }
+}
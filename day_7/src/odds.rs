@@ -0,0 +1,187 @@
+//! How likely each kind of hand is and how a hand fares against others,
+//! when every card of a hand is any card of the rules' order with the same
+//! chance (as if each came from its own full deck). Small games get every
+//! possible hand looked at, big ones a random sample.
+
+use crate::hand::Hand;
+use crate::rules::Rules;
+
+/// Games with more possible hands than this get sampled.
+pub const EXACT_LIMIT: u64 = 20_000_000;
+/// Hands sampled when there are too many to look at.
+pub const SAMPLES: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Every possible hand once.
+    Exact,
+    /// Random hands from a fixed seed.
+    Sample { hands: u64, seed: u64 },
+}
+
+impl Method {
+    /// Exact unless that's more than `EXACT_LIMIT` hands, or `samples` asks
+    /// for a sample.
+    pub fn choose(rules: &Rules, samples: Option<u64>) -> Result<Self, String> {
+        Ok(match samples {
+            Some(0) => return Err("can't sample no hands".to_owned()),
+            Some(hands) => Method::Sample { hands, seed: 1 },
+            None if possible_hands(rules).is_some_and(|n| n <= EXACT_LIMIT) => Method::Exact,
+            None => Method::Sample { hands: SAMPLES, seed: 1 },
+        })
+    }
+}
+
+/// `None` if it doesn't fit.
+pub fn possible_hands(rules: &Rules) -> Option<u64> {
+    (rules.order.len() as u64).checked_pow(rules.hand_size as u32)
+}
+
+/// Calls `f` on every hand, or on a sample of them.
+fn each_hand(rules: &Rules, method: Method, mut f: impl FnMut(Hand)) {
+    let n = rules.order.len();
+    match method {
+        Method::Exact => {
+            let mut ranks = vec![0; rules.hand_size];
+            loop {
+                f(Hand::from_ranks(rules, ranks.clone()));
+                // Counts up like an odometer, the last card fastest.
+                let Some(i) = ranks.iter().rposition(|&r| r + 1 < n) else {
+                    return;
+                };
+                ranks[i] += 1;
+                ranks[i + 1..].fill(0);
+            }
+        }
+        Method::Sample { hands, seed } => {
+            let mut state = seed;
+            let mut next = || {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 33) as usize % n
+            };
+            for _ in 0..hands {
+                f(Hand::from_ranks(rules, (0..rules.hand_size).map(|_| next()).collect()));
+            }
+        }
+    }
+}
+
+/// How many of the hands looked at were of each kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KindOdds {
+    pub hands: u64,
+    pub exact: bool,
+    /// Indexed like the rules' kinds.
+    pub counts: Vec<u64>,
+}
+
+impl KindOdds {
+    pub fn probability(&self, kind: usize) -> f64 {
+        self.counts[kind] as f64 / self.hands as f64
+    }
+}
+
+pub fn kind_odds(rules: &Rules, method: Method) -> KindOdds {
+    let mut counts = vec![0; rules.kinds.len()];
+    let mut hands = 0;
+    each_hand(rules, method, |hand| {
+        counts[hand.kind] += 1;
+        hands += 1;
+    });
+    KindOdds { hands, exact: method == Method::Exact, counts }
+}
+
+/// One hand against the hands looked at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub hands: u64,
+    pub exact: bool,
+    /// Hands it beats.
+    pub beaten: u64,
+    /// The same cards, a tie.
+    pub tied: u64,
+}
+
+impl Standing {
+    pub fn win_chance(&self) -> f64 {
+        self.beaten as f64 / self.hands as f64
+    }
+
+    /// Expected rank among itself and `opponents` random hands, 1 being
+    /// the weakest as when paying out bids. A tie counts half.
+    pub fn expected_rank(&self, opponents: u64) -> f64 {
+        1.0 + opponents as f64 * (self.beaten as f64 + self.tied as f64 / 2.0) / self.hands as f64
+    }
+}
+
+pub fn standing(rules: &Rules, hand: &Hand, method: Method) -> Standing {
+    let mut ret = Standing { hands: 0, exact: method == Method::Exact, beaten: 0, tied: 0 };
+    each_hand(rules, method, |other| {
+        ret.hands += 1;
+        match hand.cmp(&other) {
+            std::cmp::Ordering::Greater => ret.beaten += 1,
+            std::cmp::Ordering::Equal => ret.tied += 1,
+            std::cmp::Ordering::Less => {}
+        }
+    });
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn five_cards_out_of_thirteen() {
+        let odds = kind_odds(&Rules::part1(), Method::Exact);
+        assert_eq!(odds.hands, 371293);
+        assert_eq!(odds.counts, vec![154440, 171600, 25740, 17160, 1560, 780, 13]);
+
+        // Jokers only ever make hands better, so the weak kinds shrink.
+        let jokers = kind_odds(&Rules::part2(), Method::Exact);
+        assert_eq!(jokers.hands, odds.hands);
+        assert_eq!(jokers.counts[6], 12 + 12 * 5 + 12 * 10 + 12 * 10 + 12 * 5 + 1);
+        assert!(jokers.counts[0] < odds.counts[0]);
+        assert_eq!(jokers.counts.iter().sum::<u64>(), jokers.hands);
+        assert_eq!(kind_odds(&Rules::part2().without_wild(), Method::Exact), odds);
+    }
+
+    #[test]
+    fn sampling_gets_close() {
+        let rules = Rules::part2();
+        let exact = kind_odds(&rules, Method::Exact);
+        let sample = kind_odds(&rules, Method::Sample { hands: 100_000, seed: 7 });
+        assert!(!sample.exact);
+        for kind in 0..rules.kinds.len() {
+            assert!((exact.probability(kind) - sample.probability(kind)).abs() < 0.01, "{}", rules.kinds[kind].name);
+        }
+    }
+
+    #[test]
+    fn choose() {
+        assert_eq!(Method::choose(&Rules::part1(), None), Ok(Method::Exact));
+        assert_eq!(Method::choose(&Rules::part1(), Some(10)), Ok(Method::Sample { hands: 10, seed: 1 }));
+        // No hands would make every chance 0/0.
+        assert!(Method::choose(&Rules::part1(), Some(0)).is_err());
+        let big = Rules { hand_size: 40, ..Rules::part1() };
+        assert_eq!(possible_hands(&big), None);
+        assert_eq!(Method::choose(&big, None), Ok(Method::Sample { hands: SAMPLES, seed: 1 }));
+    }
+
+    #[test]
+    fn ranks() {
+        let rules = Rules::part1();
+        let best = standing(&rules, &Hand::new(&rules, "AAAAA").unwrap(), Method::Exact);
+        assert_eq!((best.beaten, best.tied), (371292, 1));
+        assert_eq!(best.expected_rank(1), 2.0 - 0.5 / 371293.0);
+        let worst = standing(&rules, &Hand::new(&rules, "23456").unwrap(), Method::Exact);
+        assert_eq!((worst.beaten, worst.tied), (0, 1));
+        assert_eq!(worst.expected_rank(0), 1.0);
+
+        // With jokers JJJJJ is the weakest five of a kind.
+        let rules = Rules::part2();
+        let jokers = standing(&rules, &Hand::new(&rules, "JJJJJ").unwrap(), Method::Exact);
+        let fives = kind_odds(&rules, Method::Exact).counts[6];
+        assert_eq!(jokers.beaten, 371293 - fives);
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::ParseError;
+
+use crate::hand::winnings;
+use crate::rules::Rules;
+
+pub fn solve(contents: &str) -> Result<u64, ParseError> {
+    winnings(&Rules::part1(), contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::Hand;
+
+    fn hand(s: &str) -> Hand {
+        Hand::new(&Rules::part1(), s).unwrap()
+    }
+
+    fn kind(s: &str) -> String {
+        hand(s).kind_name(&Rules::part1()).to_owned()
+    }
+
+    #[test]
+    fn hand_from_str() {
+        let hand = hand("32T3K");
+        assert_eq!(hand.cards, vec![ '3', '2', 'T', '3', 'K' ]);
+    }
+
+    #[test]
+    fn test_5_of_a_kind() {
+        assert_eq!(kind("AAAAA"), "FiveOfAKind");
+    }
+
+    #[test]
+    fn test_4_of_a_kind() {
+        assert_eq!(kind("AAAAK"), "FourOfAKind");
+        assert_eq!(kind("QQKQQ"), "FourOfAKind");
+    }
+
+    #[test]
+    fn test_3_of_a_kind() {
+        assert_eq!(kind("2232K"), "ThreeOfAKind");
+        assert_eq!(kind("4K5KK"), "ThreeOfAKind");
+        assert_eq!(kind("34544"), "ThreeOfAKind");
+    }
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(kind("5K5K5"), "FullHouse");
+        assert_eq!(kind("K5K5K"), "FullHouse");
+    }
+
+    #[test]
+    fn test_two_pair() {
+        assert_eq!(kind("22344"), "TwoPair");
+        assert_eq!(kind("22334"), "TwoPair");
+        assert_eq!(kind("23344"), "TwoPair");
+    }
+
+    #[test]
+    fn test_pair() {
+        assert_eq!(kind("22KQA"), "OnePair");
+        assert_eq!(kind("K22QA"), "OnePair");
+        assert_eq!(kind("KQ22A"), "OnePair");
+        assert_eq!(kind("KQA22"), "OnePair");
+        assert_eq!(kind("KQAJ2"), "HighCard");
+    }
+
+    #[test]
+    fn test_order() {
+        assert!(hand("AAAAA") > hand("AAAAK"));
+        assert!(hand("AAAAA") > hand("KKKKK"));
+
+        let (hand, hand2) = (hand("33332"), hand("2AAAA"));
+        assert_eq!(hand.kind, hand2.kind);
+        assert!(hand > hand2);
+
+        let names = Rules::part1().kinds.iter().map(|k| k.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names, ["HighCard", "OnePair", "TwoPair", "ThreeOfAKind", "FullHouse", "FourOfAKind", "FiveOfAKind"]);
+    }
+
+    #[test]
+    fn test_points() {
+        let rules = Rules::part1();
+        for (points, card) in (2..).zip("23456789TJQKA".chars()) {
+            assert_eq!(rules.rank(card), Some(points - 2));
+        }
+        assert_eq!(rules.rank('X'), None);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(include_str!("../input2.txt")).unwrap(), 6440);
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_common::ParseError;
+
+use crate::hand::winnings;
+use crate::rules::Rules;
+
+pub fn solve(contents: &str) -> Result<u64, ParseError> {
+    winnings(&Rules::part2(), contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::Hand;
+
+    fn hand(s: &str) -> Hand {
+        Hand::new(&Rules::part2(), s).unwrap()
+    }
+
+    fn kind(s: &str) -> String {
+        hand(s).kind_name(&Rules::part2()).to_owned()
+    }
+
+    #[test]
+    fn hand_from_str() {
+        let hand = hand("32T3K");
+        assert_eq!(hand.cards, vec![ '3', '2', 'T', '3', 'K' ]);
+    }
+
+    #[test]
+    fn test_5_of_a_kind() {
+        assert_eq!(kind("AAAAA"), "FiveOfAKind");
+        assert_eq!(kind("AJJJJ"), "FiveOfAKind");
+        assert_eq!(kind("JJJJJ"), "FiveOfAKind");
+    }
+
+    #[test]
+    fn test_4_of_a_kind() {
+        assert_eq!(kind("AAAAK"), "FourOfAKind");
+        assert_eq!(kind("QQKQQ"), "FourOfAKind");
+        assert_eq!(kind("T55J5"), "FourOfAKind");
+        assert_eq!(kind("QJJQ2"), "FourOfAKind");
+    }
+
+    #[test]
+    fn test_3_of_a_kind() {
+        assert_eq!(kind("2232K"), "ThreeOfAKind");
+        assert_eq!(kind("4K5KK"), "ThreeOfAKind");
+        assert_eq!(kind("34544"), "ThreeOfAKind");
+        assert_eq!(kind("JJ345"), "ThreeOfAKind");
+        assert_eq!(kind("8A68J"), "ThreeOfAKind");
+    }
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(kind("5K5K5"), "FullHouse");
+        assert_eq!(kind("K5K5K"), "FullHouse");
+        assert_eq!(kind("2T2JT"), "FullHouse");
+    }
+
+    #[test]
+    fn test_two_pair() {
+        assert_eq!(kind("22344"), "TwoPair");
+        assert_eq!(kind("22334"), "TwoPair");
+        assert_eq!(kind("23344"), "TwoPair");
+    }
+
+    #[test]
+    fn test_pair() {
+        assert_eq!(kind("22KQA"), "OnePair");
+        assert_eq!(kind("K22QA"), "OnePair");
+        assert_eq!(kind("KQ22A"), "OnePair");
+        assert_eq!(kind("KQA22"), "OnePair");
+        assert_eq!(kind("KQAJ2"), "OnePair");
+        assert_eq!(kind("KT93J"), "OnePair");
+    }
+
+    #[test]
+    fn test_order() {
+        assert!(hand("AAAAA") > hand("AAAAK"));
+        assert!(hand("AAAAA") > hand("KKKKK"));
+
+        let (hand3, hand4) = (hand("33332"), hand("2AAAA"));
+        assert_eq!(hand3.kind, hand4.kind);
+        assert!(hand3 > hand4);
+
+        // A joker is the weakest card when breaking ties.
+        let (hand3, hand4) = (hand("JKKK2"), hand("QQQQ2"));
+        assert_eq!(hand3.kind, hand4.kind);
+        assert!(hand3 < hand4);
+    }
+
+    #[test]
+    fn test_points() {
+        let rules = Rules::part2();
+        assert_eq!(rules.rank('J'), Some(0));
+        for (points, card) in (2..).zip("23456789T".chars()) {
+            assert_eq!(rules.rank(card), Some(points - 1));
+        }
+        assert_eq!((rules.rank('Q'), rules.rank('K'), rules.rank('A')), (Some(10), Some(11), Some(12)));
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(include_str!("../input2.txt")).unwrap(), 5905);
+    }
+}
@@ -0,0 +1,186 @@
+//! A Camel Cards variant: which cards there are and how they rank, which are
+//! wild, how many make a hand and what kinds of hand beat what. See
+//! `rules/part1.toml` for the file format.
+
+use toml::{Table, Value};
+
+/// A kind of hand, by the groups of equal cards it needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandKind {
+    pub name: String,
+    /// Largest first.
+    pub groups: Vec<usize>,
+}
+
+impl HandKind {
+    /// Wild cards it takes to turn `counts` (largest first, no wild cards
+    /// counted) into this kind. Biggest groups get paired up with biggest
+    /// groups, wild cards fill what's missing.
+    pub fn wild_needed(&self, counts: &[usize]) -> usize {
+        self.groups.iter().enumerate().map(|(i, &g)| g.saturating_sub(counts.get(i).copied().unwrap_or(0))).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Weakest first.
+    pub order: Vec<char>,
+    pub wild: Vec<char>,
+    pub hand_size: usize,
+    /// Weakest first.
+    pub kinds: Vec<HandKind>,
+}
+
+impl Rules {
+    pub fn part1() -> Self {
+        Rules::from_toml(include_str!("../rules/part1.toml")).unwrap()
+    }
+
+    pub fn part2() -> Self {
+        Rules::from_toml(include_str!("../rules/part2.toml")).unwrap()
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+        let cards = |key: &str| match table.get(key) {
+            Some(Value::String(s)) => Ok(s.chars().collect::<Vec<_>>()),
+            _ => Err(format!("{} should be a string of cards", key)),
+        };
+        let order = cards("order")?;
+        let wild = match table.get("wild") {
+            None => vec![],
+            Some(_) => cards("wild")?,
+        };
+        let hand_size = match table.get("hand_size") {
+            Some(Value::Integer(n @ 1..)) => *n as usize,
+            _ => return Err("hand_size should be a number above 0".to_owned()),
+        };
+        let Some(Value::Array(list)) = table.get("kinds") else {
+            return Err("kinds should be a list".to_owned());
+        };
+        let mut kinds = vec![];
+        for (i, kind) in list.iter().enumerate() {
+            let name = kind.get("name").and_then(Value::as_str).ok_or(format!("kind {} has no name", i + 1))?;
+            let mut groups = vec![];
+            for g in kind.get("groups").and_then(Value::as_array).ok_or(format!("{} has no groups", name))? {
+                match g.as_integer() {
+                    Some(n @ 1..) => groups.push(n as usize),
+                    _ => return Err(format!("{}: groups should be numbers above 0", name)),
+                }
+            }
+            groups.sort_unstable_by(|a, b| b.cmp(a));
+            kinds.push(HandKind { name: name.to_owned(), groups });
+        }
+        Rules::new(order, wild, hand_size, kinds)
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Rules::from_toml(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub fn new(order: Vec<char>, wild: Vec<char>, hand_size: usize, kinds: Vec<HandKind>) -> Result<Self, String> {
+        if order.is_empty() {
+            return Err("there are no cards".to_owned());
+        }
+        if let Some((i, c)) = order.iter().enumerate().find(|(i, c)| order[..*i].contains(c)) {
+            return Err(format!("{} is in the order twice, at {} and {}", c, order.iter().position(|x| x == c).unwrap() + 1, i + 1));
+        }
+        if let Some(c) = wild.iter().find(|c| !order.contains(c)) {
+            return Err(format!("wild card {} isn't in the order", c));
+        }
+        if hand_size == 0 {
+            return Err("hands need at least one card".to_owned());
+        }
+        for kind in &kinds {
+            if kind.groups.iter().sum::<usize>() > hand_size {
+                return Err(format!("{} needs more than {} cards", kind.name, hand_size));
+            }
+        }
+        // Every hand has a group of at least one card, but not always two
+        // groups of them: AAAAA isn't [1, 1].
+        if !kinds.iter().any(|k| k.groups.iter().sum::<usize>() <= 1) {
+            return Err("some hands would be no kind, add one like { name = \"HighCard\", groups = [1] }".to_owned());
+        }
+        Ok(Rules { order, wild, hand_size, kinds })
+    }
+
+    /// 0 for the weakest card.
+    pub fn rank(&self, card: char) -> Option<usize> {
+        self.order.iter().position(|&c| c == card)
+    }
+
+    pub fn is_wild(&self, card: char) -> bool {
+        self.wild.contains(&card)
+    }
+
+    /// Strongest kind, as an index into `kinds`, for a hand with `counts`
+    /// of each non-wild card (in any order) and `wild` wild cards.
+    pub fn classify(&self, counts: &[usize], wild: usize) -> usize {
+        let mut counts = counts.iter().copied().filter(|&c| c > 0).collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        // `new` makes sure some kind fits every hand.
+        self.kinds.iter().rposition(|k| k.wild_needed(&counts) <= wild).unwrap()
+    }
+
+    /// The same game with every card standing for itself.
+    pub fn without_wild(&self) -> Self {
+        Rules { wild: vec![], ..self.clone() }
+    }
+
+    pub fn order_string(&self) -> String {
+        self.order.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_rules() {
+        let err = |text: &str| Rules::from_toml(text).unwrap_err();
+        let kinds = "kinds = [{ name = \"HighCard\", groups = [1] }]";
+        assert_eq!(err(&format!("order = \"AKA\"\nhand_size = 5\n{}", kinds)), "A is in the order twice, at 1 and 3");
+        assert_eq!(err(&format!("order = \"AK\"\nwild = \"J\"\nhand_size = 5\n{}", kinds)), "wild card J isn't in the order");
+        assert_eq!(err(&format!("order = \"AK\"\nhand_size = 0\n{}", kinds)), "hand_size should be a number above 0");
+        assert_eq!(err("order = \"AK\"\nhand_size = 3\nkinds = [{ name = \"Six\", groups = [6] }]"), "Six needs more than 3 cards");
+        assert!(err("order = \"AK\"\nhand_size = 3\nkinds = [{ name = \"Pair\", groups = [2] }]").starts_with("some hands would be no kind"));
+        assert!(err("order = \"AK\"\nhand_size = 5\nkinds = [{ name = \"Spread\", groups = [1, 1] }]").starts_with("some hands would be no kind"));
+        assert!(Rules::from_toml(&format!("order = \"AK\"\nhand_size = 5\n{}", kinds)).is_ok());
+        assert!(Rules::from_toml("order = \"AK\"\nhand_size = 5\nkinds = [{ name = \"Nothing\", groups = [] }]").is_ok());
+    }
+
+    #[test]
+    fn wild_cards_join_the_biggest_group() {
+        let rules = Rules::part2();
+        let name = |counts: &[usize], wild| rules.kinds[rules.classify(counts, wild)].name.as_str();
+        assert_eq!(name(&[], 5), "FiveOfAKind");
+        assert_eq!(name(&[1, 1], 3), "FourOfAKind");
+        assert_eq!(name(&[2, 2], 1), "FullHouse");
+        assert_eq!(name(&[1, 1, 1], 2), "ThreeOfAKind");
+        assert_eq!(name(&[1, 2, 1], 1), "ThreeOfAKind");
+        assert_eq!(name(&[1, 1, 1, 1], 1), "OnePair");
+        assert_eq!(name(&[1, 1, 1, 1, 1], 0), "HighCard");
+    }
+
+    /// A bigger game than five cards out of thirteen.
+    #[test]
+    fn six_card_hands() {
+        let rules = Rules::from_toml(
+            "order = \"123456789*\"\nwild = \"*\"\nhand_size = 6\nkinds = [\n\
+             { name = \"Nothing\", groups = [] },\n\
+             { name = \"ThreePairs\", groups = [2, 2, 2] },\n\
+             { name = \"TwoTriples\", groups = [3, 3] },\n\
+             { name = \"Six\", groups = [6] },\n]",
+        )
+        .unwrap();
+        assert_eq!(rules.classify(&[2, 2, 1, 1], 0), 0);
+        assert_eq!(rules.classify(&[2, 2, 1], 1), 1);
+        assert_eq!(rules.classify(&[2, 2, 1], 0), 0);
+        assert_eq!(rules.classify(&[3, 2], 1), 2);
+        assert_eq!(rules.classify(&[2, 1], 3), 2);
+        assert_eq!(rules.classify(&[4, 1], 1), 0);
+        assert_eq!(rules.classify(&[], 6), 3);
+    }
+}
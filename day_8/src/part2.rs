@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::{ParseError, Scanner};
+use num::integer::lcm;
+use tracing::{debug, trace};
+
+fn count_steps_to_end_state(start_node: &str, seq: &str, matrix: &HashMap::<String, (String, String)>) -> usize {
+    let mut seq_idx = 0usize;
+    let mut ret_step_cnt = 0usize;
+
+    let mut cur_node = matrix.get_key_value(start_node).unwrap();
+    while !cur_node.0.ends_with('Z') || seq_idx != 0 {
+        let nxt = match seq.chars().nth(seq_idx).unwrap() {
+            'L' => cur_node.1.0.as_str(),
+            'R' => cur_node.1.1.as_str(),
+            _ => panic!("Should never happen")
+        };
+        cur_node = matrix.get_key_value(nxt).unwrap();
+        seq_idx = (seq_idx + 1) % seq.len();
+        ret_step_cnt += 1;
+    }
+    debug!("{}: {}, {}", start_node, seq_idx, ret_step_cnt);
+    assert!(seq_idx == 0);
+    ret_step_cnt
+}
+fn count_steps(seq: &str, matrix: HashMap::<String, (String, String)>) -> usize {
+    let start_nodes = matrix
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|k| k.as_str())
+        .collect::<Vec<&str>>();
+
+    start_nodes.iter()
+        .map(|str_node| count_steps_to_end_state(str_node, seq, &matrix))
+        .fold(1, lcm)
+}
+
+
+fn parse_sequence(line: &str) -> Result<&str, ParseError> {
+    let mut s = Scanner::new(line);
+    let seq = s.take_while1("L or R", |c| c == 'L' || c == 'R')?;
+    if !s.is_empty() {
+        return Err(s.error("L or R"));
+    }
+    Ok(seq)
+}
+
+// AAA = (BBB, CCC)
+fn parse_node(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let mut s = Scanner::new(line);
+    let node = s.word()?;
+    s.tag(" = (")?;
+    let left = s.word()?;
+    s.tag(", ")?;
+    let right = s.word()?;
+    s.tag(")")?;
+    s.end()?;
+    Ok((node, left, right))
+}
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    let lines = input.split('\n').filter(|l| !l.is_empty());
+
+    let mut seq = "";
+    let mut nodes = vec![];
+    for (idx,line) in lines.enumerate() {
+        if idx == 0 {
+            seq = parse_sequence(line).map_err(|e| e.locate(input))?;
+            continue;
+        }
+        nodes.push(parse_node(line).map_err(|e| e.locate(input))?);
+    }
+    if seq.is_empty() {
+        return Err(ParseError::end_of_input("L or R", input));
+    }
+
+    let names = nodes.iter().map(|&(node, _, _)| node).collect::<HashSet<&str>>();
+    let mut adj_matrix = HashMap::new();
+    for (node, left_node, right_node) in nodes {
+        // We could end up walking to any of them
+        for next in [left_node, right_node] {
+            if !names.contains(next) {
+                return Err(ParseError::new("a defined node", next).locate(input));
+            }
+        }
+        adj_matrix.insert(String::from(node), (String::from(left_node), String::from(right_node)));
+        trace!("{} = ({}, {})", node, left_node, right_node);
+    }
+
+    Ok(count_steps(seq, adj_matrix))
+}
@@ -0,0 +1,19 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn solve_part1(input: &str) -> Result<i32, ParseError> {
+        part1::solve(input)
+    }
+
+    fn solve_part2(input: &str) -> Result<i32, ParseError> {
+        part2::solve(input)
+    }
+}
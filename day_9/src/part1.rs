@@ -0,0 +1,108 @@
+use core::fmt;
+use aoc_common::{ParseError, Scanner};
+use std::cell::RefCell;
+
+#[derive(Debug, Clone)]
+struct Sequence {
+    values: Vec<i32>
+}
+
+impl Sequence {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let mut values = vec![ scanner.signed::<i32>()? ];
+        while scanner.eat(" ") {
+            scanner.spaces();
+            values.push(scanner.signed::<i32>()?);
+        }
+        scanner.end()?;
+        Ok(Self {
+            values
+        })
+    }
+
+    fn derive(&self) -> Self {
+        Self {
+            values: self.values.windows(2).map(|arr| arr.last().unwrap() - arr.first().unwrap()).collect()
+        }
+    }
+
+    fn extrapolate(&self) -> Self {
+        let mut ders = vec![ RefCell::new(self.clone()) ];
+
+        // Derive until we get a sequence of all 0's
+        while !ders.last().unwrap().borrow().values.iter().all(|&n| n == 0) {
+            let new_val = RefCell::new(ders.last().unwrap().borrow().derive());
+            ders.push(new_val);
+        }
+
+        // Extrapolate new value
+        for (idx,seq) in ders.iter().rev().skip(1).enumerate() {
+            let prev_seq = ders.iter().rev().nth(idx).unwrap();
+            // A single value derives to nothing, which extrapolates like all 0's
+            let new_val = prev_seq.borrow().values.last().unwrap_or(&0)
+                    + seq.borrow().values.last().unwrap();
+            seq.borrow_mut().values.push(new_val);
+        }
+
+        ders.into_iter().next().unwrap().into_inner()
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.values)
+    }
+}
+
+pub fn solve(input: &str) -> Result<i32, ParseError> {
+   let lines = input.split('\n').filter(|l| !l.is_empty());
+
+   let mut sum = 0;
+   for l in lines {
+       sum += *Sequence::new(l).map_err(|e| e.locate(input))?.extrapolate().values.last().unwrap();
+   }
+   Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_sequence() {
+        let seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        assert_eq!(seq.values, vec![0, 3, 6, 9, 12, 15]); 
+    }
+    #[test]
+    fn test_derive_sequence() {
+        let seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        let der_seq = seq.derive();
+        assert_eq!(der_seq.values.len(), 5);
+        assert_eq!(der_seq.values, vec![3, 3, 3, 3, 3]);
+    }
+    #[test]
+    fn test_extrapolate() {
+        let seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        let new = seq.extrapolate();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ 0, 3, 6, 9, 12, 15, 18 ]);
+
+        let seq = Sequence::new("1 3 6 10 15 21").unwrap();
+        let new = seq.extrapolate();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ 1, 3, 6, 10, 15, 21, 28 ]);
+
+        let seq = Sequence::new("10 13 16 21 30 45").unwrap();
+        let new = seq.extrapolate();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ 10, 13, 16, 21, 30, 45, 68 ]);
+    }
+    #[test]
+    fn test_rejects_garbage() {
+        assert_eq!(Sequence::new("1 2 x").unwrap_err().found, "x");
+        let err = solve("0 3 6\nhello world\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(solve("5\n").unwrap(), 5);
+    }
+}
@@ -0,0 +1,122 @@
+use core::fmt;
+use aoc_common::{ParseError, Scanner};
+use std::{cell::RefCell, ops::Deref};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+struct Sequence {
+    values: VecDeque<i32>
+}
+
+impl Sequence {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let mut values = vec![ scanner.signed::<i32>()? ];
+        while scanner.eat(" ") {
+            scanner.spaces();
+            values.push(scanner.signed::<i32>()?);
+        }
+        scanner.end()?;
+        Ok(Self {
+            values: values.into()
+        })
+    }
+
+    fn derive(&mut self) -> Self {
+        Self {
+            values: self.values.make_contiguous().windows(2).map(|arr| arr.last().unwrap() - arr.first().unwrap()).collect()
+        }
+    }
+
+    fn all_derivatives(&mut self) -> Vec<RefCell<Self>> {
+        let mut ders = vec![ RefCell::new(self.clone()) ];
+
+        // Derive until we get a sequence of all 0's
+        while !ders.last().unwrap().borrow().values.iter().all(|&n| n == 0) {
+            let new_val = RefCell::new(ders.last().unwrap().borrow_mut().derive());
+            ders.push(new_val);
+        }
+
+        ders
+    }
+
+    fn extrapolate_impl(&mut self, 
+        f: fn(&Sequence, &Sequence) -> i32,
+        apply_new_val: fn(&mut Sequence, i32)
+    ) -> Self {
+        let ders = self.all_derivatives();
+        // Extrapolate new value
+        for (idx,seq) in ders.iter().rev().skip(1).enumerate() {
+            let prev_seq = ders.iter().rev().nth(idx).unwrap();
+            let new_val = f(prev_seq.borrow().deref(), seq.borrow().deref());
+            apply_new_val(&mut seq.borrow_mut(), new_val);
+        }
+
+        ders.into_iter().next().unwrap().into_inner()
+    }
+
+    #[allow(dead_code)]
+    fn extrapolate(&mut self) -> Self {
+        self.extrapolate_impl(
+            |prev_seq, seq| prev_seq.values.back().unwrap_or(&0) + seq.values.back().unwrap(),
+            |seq, val| seq.values.push_back(val)
+        )
+    }
+    fn extrapolate_back(&mut self) -> Self {
+        self.extrapolate_impl(
+            |prev_seq, seq| seq.values.front().unwrap() - prev_seq.values.front().unwrap_or(&0),
+            |seq, val| seq.values.push_front(val)
+        )
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.values)
+    }
+}
+
+pub fn solve(input: &str) -> Result<i32, ParseError> {
+   let lines = input.split('\n').filter(|l| !l.is_empty());
+
+   let mut sum = 0;
+   for l in lines {
+       sum += *Sequence::new(l).map_err(|e| e.locate(input))?.extrapolate_back().values.front().unwrap();
+   }
+   Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_sequence() {
+        let seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        assert_eq!(seq.values, vec![0, 3, 6, 9, 12, 15]); 
+    }
+    #[test]
+    fn test_derive_sequence() {
+        let mut seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        let der_seq = seq.derive();
+        assert_eq!(der_seq.values.len(), 5);
+        assert_eq!(der_seq.values, vec![3, 3, 3, 3, 3]);
+    }
+    #[test]
+    fn test_extrapolate() {
+        let mut seq = Sequence::new("0 3 6 9 12 15").unwrap();
+        let new = seq.extrapolate_back();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ -3, 0, 3, 6, 9, 12, 15 ]);
+
+        let mut seq = Sequence::new("1 3 6 10 15 21").unwrap();
+        let new = seq.extrapolate_back();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ 0, 1, 3, 6, 10, 15, 21 ]);
+
+        let mut seq = Sequence::new("10 13 16 21 30 45").unwrap();
+        let new = seq.extrapolate_back();
+        assert_eq!(new.values.len(), seq.values.len() + 1);
+        assert_eq!(new.values, vec![ 5, 10, 13, 16, 21, 30, 45 ]);
+    }
+}
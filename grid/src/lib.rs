@@ -0,0 +1,309 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+
+mod point;
+
+pub use point::{Point, Vector};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// Row `line` (1-based) has no cells.
+    EmptyRow { line: usize },
+    /// Row `line` (1-based) has a different length than the first row.
+    Ragged { line: usize, expected: usize, found: usize },
+    /// The cell parser rejected a character. `line`/`column` are 1-based.
+    InvalidCell { line: usize, column: usize, ch: char },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::EmptyRow { line } => write!(f, "line {}: row is empty", line),
+            GridError::Ragged { line, expected, found } =>
+                write!(f, "line {}: expected {} columns, found {}", line, expected, found),
+            GridError::InvalidCell { line, column, ch } =>
+                write!(f, "line {}, column {}: unexpected '{}'", line, column, ch),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl From<GridError> for ParseError {
+    fn from(e: GridError) -> Self {
+        match e {
+            GridError::Empty => ParseError::end_of_input("at least one row", ""),
+            GridError::EmptyRow { line } => ParseError::at(line, 1, "grid cell", "end of line"),
+            GridError::Ragged { line, expected, found } =>
+                ParseError::at(line, found.min(expected) + 1, format!("{} columns", expected), format!("{} columns", found)),
+            GridError::InvalidCell { line, column, ch } =>
+                ParseError::at(line, column, "grid cell", ch),
+        }
+    }
+}
+
+/// Rectangular grid stored row-major.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grids have at least one cell, like parsed ones do.
+    pub fn new(width: usize, height: usize, fill: T) -> Result<Self, GridError> {
+        if height == 0 {
+            return Err(GridError::Empty);
+        }
+        if width == 0 {
+            return Err(GridError::EmptyRow { line: 1 });
+        }
+        Ok(Grid { width, height, cells: vec![fill; width * height] })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().ok_or(GridError::Empty)?.len();
+        if width == 0 {
+            return Err(GridError::EmptyRow { line: 1 });
+        }
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (idx, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged { line: idx + 1, expected: width, found: row.len() });
+            }
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Parses one cell per character, one row per line.
+    pub fn parse(text: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::try_parse(text, |c| Some(f(c)))
+    }
+
+    /// Like `parse`, but `f` may reject a character.
+    pub fn try_parse(text: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let rows = text
+            .lines()
+            .enumerate()
+            .map(|(line, l)| l
+                .chars()
+                .enumerate()
+                .map(|(column, ch)| f(ch).ok_or(GridError::InvalidCell { line: line + 1, column: column + 1, ch }))
+                .collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.0 < self.width && p.1 < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[p.1 * self.width + p.0])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    /// Treats the grid as tiling the whole plane.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[Point(x, y)]
+    }
+
+    /// `p + v` if that's still inside the grid.
+    pub fn step(&self, p: Point, v: Vector) -> Option<Point> {
+        p.checked_add(v).filter(|&n| self.contains(n))
+    }
+
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Vector::ORTHOGONAL.into_iter().filter_map(move |v| self.step(p, v))
+    }
+
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Vector::ALL.into_iter().filter_map(move |v| self.step(p, v))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// All points, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|c| c.cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let cells = self.columns().flat_map(|c| c.cloned().collect::<Vec<_>>().into_iter().rev()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{} out of bounds for {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| panic!("{} out of bounds for {}x{} grid", p, width, height))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_and_display() {
+        let g: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Point(2, 1)], 'f');
+        assert_eq!(g.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("abc\nde".parse::<Grid<char>>(), Err(GridError::Ragged { line: 2, expected: 3, found: 2 }));
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert_eq!("\nabc".parse::<Grid<char>>(), Err(GridError::EmptyRow { line: 1 }));
+        assert_eq!(Grid::<u8>::from_rows(vec![vec![], vec![]]), Err(GridError::EmptyRow { line: 1 }));
+        let err = Grid::try_parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err, GridError::InvalidCell { line: 2, column: 2, ch: 'x' });
+    }
+
+    #[test]
+    fn new_needs_cells() {
+        assert_eq!(Grid::new(0, 3, '.'), Err(GridError::EmptyRow { line: 1 }));
+        assert_eq!(Grid::new(3, 0, '.'), Err(GridError::Empty));
+        let g = Grid::new(2, 1, '.').unwrap();
+        assert_eq!((g.rows().count(), g.column(1).count(), *g.get_wrapping(-1, 5)), (1, 1, '.'));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let g: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!(g.neighbors4(Point(0, 0)).collect::<Vec<_>>(), vec![Point(1, 0), Point(0, 1)]);
+        assert_eq!(g.neighbors8(Point(2, 1)).count(), 3);
+        assert_eq!(g.neighbors4(Point(1, 1)).count(), 3);
+        assert_eq!(g.step(Point(2, 0), Vector::RIGHT), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let g: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!(*g.get_wrapping(-1, -1), 'f');
+        assert_eq!(*g.get_wrapping(3, 2), 'a');
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        assert_eq!(g.columns().count(), 3);
+        assert_eq!(g.position(|&c| c == 'e'), Some(Point(1, 1)));
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let g: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Position in a grid, `(x, y)` with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point(pub usize, pub usize);
+
+/// Offset between two points, `(dx, dy)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector(pub isize, pub isize);
+
+impl Vector {
+    pub const UP: Vector = Vector(0, -1);
+    pub const DOWN: Vector = Vector(0, 1);
+    pub const LEFT: Vector = Vector(-1, 0);
+    pub const RIGHT: Vector = Vector(1, 0);
+
+    /// The 4-neighborhood.
+    pub const ORTHOGONAL: [Vector; 4] = [Vector::RIGHT, Vector::LEFT, Vector::DOWN, Vector::UP];
+
+    /// The 8-neighborhood, row by row starting top left.
+    pub const ALL: [Vector; 8] = [
+        Vector(-1, -1), Vector(0, -1), Vector(1, -1),
+        Vector(-1, 0), Vector(1, 0),
+        Vector(-1, 1), Vector(0, 1), Vector(1, 1),
+    ];
+
+    pub fn rotate_cw(self) -> Vector {
+        Vector(-self.1, self.0)
+    }
+
+    pub fn rotate_ccw(self) -> Vector {
+        Vector(self.1, -self.0)
+    }
+}
+
+impl Point {
+    /// `None` if the result would leave the non-negative quadrant. Doesn't know
+    /// about grid bounds, see `Grid::step` for that.
+    pub fn checked_add(self, v: Vector) -> Option<Point> {
+        Some(Point(self.0.checked_add_signed(v.0)?, self.1.checked_add_signed(v.1)?))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector(self.0 as isize - rhs.0 as isize, self.1 as isize - rhs.1 as isize)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector(-self.0, -self.1)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vector(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}